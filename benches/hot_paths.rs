@@ -0,0 +1,41 @@
+// Timings for the slowest solutions, run with `cargo bench`
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use advent::day09::Board;
+use advent::day11::Grid;
+use advent::day12::Pots;
+use advent::input::read_input;
+
+// runs f until at least a second has passed and reports the mean time per run
+fn bench<T>(name: &str, mut f: impl FnMut() -> T) {
+    let start = Instant::now();
+    let mut runs = 0u32;
+    while start.elapsed() < Duration::from_secs(1) || runs < 3 {
+        black_box(f());
+        runs += 1;
+    }
+    println!(
+        "{:<40} {:>12.3?} ({} runs)",
+        name,
+        start.elapsed() / runs,
+        runs
+    );
+}
+
+fn main() {
+    bench("day09 winning_score 428/70825", || {
+        Board::new(428, 70825).winning_score()
+    });
+
+    let mut grid = Grid::new(1308);
+    bench("day11 best_complex_spot 1308", || {
+        grid.best_complex_spot().unwrap()
+    });
+
+    let input = read_input(12).unwrap();
+    let pots = Pots::new(&input).unwrap();
+    bench("day12 next_gen 1000 generations", || {
+        pots.clone().next_gen(1000)
+    });
+}
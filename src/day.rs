@@ -0,0 +1,28 @@
+use crate::error::AdventError;
+use crate::{day02, day04, day06, day07, day09, day11, day12};
+
+// A day's puzzle, with both parts solved from the puzzle input
+pub trait Day {
+    fn part1(&self, input: &str) -> Result<String, AdventError>;
+    fn part2(&self, input: &str) -> Result<String, AdventError>;
+}
+
+// every day that can be solved through Day, with its number
+pub fn all_days() -> Vec<(u32, Box<dyn Day>)> {
+    vec![
+        (2, Box::new(day02::Day02)),
+        (4, Box::new(day04::Day04)),
+        (6, Box::new(day06::Day06)),
+        (7, Box::new(day07::Day07)),
+        (9, Box::new(day09::Day09)),
+        (11, Box::new(day11::Day11)),
+        (12, Box::new(day12::Day12)),
+    ]
+}
+
+pub fn find_day(number: u32) -> Option<Box<dyn Day>> {
+    all_days()
+        .into_iter()
+        .find(|(day, _)| *day == number)
+        .map(|(_, day)| day)
+}
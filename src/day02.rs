@@ -1,55 +1,272 @@
 use std::collections::HashMap;
 
-#[allow(dead_code)]
-fn checksum(ids: &[&str]) -> usize {
-    let twos = ids.iter().filter(|s| character_counts(s, 2)).count();
+use crate::day::Day;
+use crate::error::AdventError;
 
-    let threes = ids.iter().filter(|s| character_counts(s, 3)).count();
+pub fn checksum<S: AsRef<str>>(ids: &[S]) -> usize {
+    let mut accumulator = ChecksumAccumulator::default();
+    for id in ids {
+        accumulator.feed(id.as_ref());
+    }
+    accumulator.finish()
+}
+
+// the checksum of IDs seen one at a time, without keeping them around
+#[derive(Debug, Default)]
+pub struct ChecksumAccumulator {
+    doubles: usize,
+    triples: usize,
+}
+
+impl ChecksumAccumulator {
+    pub fn feed(&mut self, id: &str) {
+        let counts = letter_frequencies(id);
+        if character_counts(&counts, 2) {
+            self.doubles += 1;
+        }
+        if character_counts(&counts, 3) {
+            self.triples += 1;
+        }
+    }
+
+    pub fn finish(&self) -> usize {
+        self.doubles * self.triples
+    }
+}
+
+// the indices of the IDs with a letter appearing exactly twice,
+// and of those with a letter appearing exactly three times
+pub fn classify<S: AsRef<str>>(ids: &[S]) -> (Vec<usize>, Vec<usize>) {
+    let mut doubles = vec![];
+    let mut triples = vec![];
+    for (i, id) in ids.iter().enumerate() {
+        let counts = letter_frequencies(id.as_ref());
+        if character_counts(&counts, 2) {
+            doubles.push(i);
+        }
+        if character_counts(&counts, 3) {
+            triples.push(i);
+        }
+    }
+    (doubles, triples)
+}
 
-    twos * threes
+// Trims each ID and optionally lowercases it before counting letters
+pub fn checksum_normalized<S: AsRef<str>>(ids: &[S], case_insensitive: bool) -> usize {
+    let normalized: Vec<String> = ids
+        .iter()
+        .map(|id| {
+            let id = id.as_ref().trim();
+            if case_insensitive {
+                id.to_lowercase()
+            } else {
+                id.to_string()
+            }
+        })
+        .collect();
+    checksum(&normalized)
 }
 
-fn character_counts(s: &str, n: usize) -> bool {
-    let matching_chars = counts(s).values().filter(|count| **count == n).count();
+// An ID containing several of the repeat values counts once towards each of them
+pub fn checksum_for<S: AsRef<str>>(ids: &[S], repeats: &[usize]) -> usize {
+    let frequencies: Vec<HashMap<char, usize>> =
+        ids.iter().map(|s| letter_frequencies(s.as_ref())).collect();
+    repeats
+        .iter()
+        .map(|n| {
+            frequencies
+                .iter()
+                .filter(|counts| character_counts(counts, *n))
+                .count()
+        })
+        .product()
+}
+
+fn character_counts(counts: &HashMap<char, usize>, n: usize) -> bool {
+    let matching_chars = counts.values().filter(|count| **count == n).count();
     matching_chars > 0
 }
 
-fn counts(s: &str) -> HashMap<char, usize> {
+pub fn letter_frequencies(s: &str) -> HashMap<char, usize> {
     let mut result: HashMap<char, usize> = HashMap::new();
     for c in s.chars() {
         result.entry(c)
-            .and_modify(|cur| *cur = *cur + 1)
+            .and_modify(|cur| *cur += 1)
             .or_insert(1);
     }
     result
 }
 
-#[allow(dead_code)]
-fn correct_id(ids: &[&str]) -> String {
+// IDs made of the same letters share a signature, their letters in sorted order
+// each signature maps to the indices of its IDs, in input order
+pub fn cluster_by_signature(ids: &[&str]) -> HashMap<String, Vec<usize>> {
+    let mut result: HashMap<String, Vec<usize>> = HashMap::new();
+    for (i, id) in ids.iter().enumerate() {
+        let mut chars: Vec<char> = id.chars().collect();
+        chars.sort_unstable();
+        result.entry(chars.into_iter().collect())
+            .or_default()
+            .push(i);
+    }
+    result
+}
+
+pub fn correct_id<S: AsRef<str>>(ids: &[S]) -> Option<String> {
+    correct_id_pair(ids).map(|(_, _, common)| common)
+}
+
+// the common string along with the position of the character that was removed
+// when one ID is a character longer, that character is the one past the end of the other
+pub fn correct_id_with_index<S: AsRef<str>>(ids: &[S]) -> Option<(String, usize)> {
+    let (i, j, _) = correct_id_pair(ids)?;
+    let a = ids[i].as_ref();
+    let mask = match_mask(a, ids[j].as_ref());
+    let index = mask.iter().position(|same| !same).unwrap_or(mask.len());
+    Some((common_chars(a, &mask), index))
+}
+
+pub fn pairs_within<S: AsRef<str>>(ids: &[S], n: usize) -> Vec<(usize, usize)> {
+    let mut result = vec![];
+    for (i, id) in ids.iter().enumerate() {
+        for (j, id2) in ids.iter().enumerate().skip(i + 1) {
+            if hamming(id.as_ref(), id2.as_ref()) <= n {
+                result.push((i, j));
+            }
+        }
+    }
+    result
+}
+
+// The first pair in input order, by the first ID and then the second, that is
+// exactly one character apart as measured by hamming. This is pairs_within for a
+// distance of exactly 1, with a faster search.
+// Two IDs of the same length differ by exactly one character if they become equal
+// once the same column is removed from both. Bucketing on that gives O(n·len) hashing
+// instead of comparing every pair. An ID one character longer than another is one
+// apart from it if the shorter one is the rest of it, which is bucketed separately.
+pub fn correct_id_pair<S: AsRef<str>>(ids: &[S]) -> Option<(usize, usize, String)> {
+    let ids: Vec<&str> = ids.iter().map(|id| id.as_ref()).collect();
+    let chars: Vec<Vec<char>> = ids.iter().map(|id| id.chars().collect()).collect();
+    let mut best: Option<(usize, usize)> = None;
+    let mut consider = |i: usize, j: usize| {
+        let pair = (usize::min(i, j), usize::max(i, j));
+        if best.is_none_or(|best| pair < best) {
+            best = Some(pair);
+        }
+    };
+
+    // within a bucket the first ID pairs with the first one that isn't identical to it,
+    // and no later pair in the bucket comes before that in input order
+    let longest = chars.iter().map(|id| id.len()).max().unwrap_or(0);
+    for column in 0..longest {
+        let mut buckets: HashMap<(usize, String), (usize, Option<usize>)> = HashMap::new();
+        for (j, id) in chars.iter().enumerate() {
+            if column >= id.len() {
+                continue;
+            }
+            let rest: String = id
+                .iter()
+                .enumerate()
+                .filter(|(k, _)| *k != column)
+                .map(|(_, c)| c)
+                .collect();
+            let bucket = buckets.entry((id.len(), rest)).or_insert((j, None));
+            if bucket.1.is_none() && chars[bucket.0][column] != id[column] {
+                bucket.1 = Some(j);
+            }
+        }
+        for (i, j) in buckets.values() {
+            if let Some(j) = j {
+                consider(*i, *j);
+            }
+        }
+    }
+
+    // the first of each ID is enough, as any later copy of it pairs later in input order
+    let mut first_of: HashMap<&str, usize> = HashMap::new();
     for (i, id) in ids.iter().enumerate() {
-        for id2 in ids.iter().skip(i + 1) {
-            let (differences, common) = string_diff(id, id2);
-            if differences == 1 {
-                return common;
+        first_of.entry(id).or_insert(i);
+    }
+    for (j, id) in ids.iter().enumerate() {
+        if let Some((last, _)) = id.char_indices().last() {
+            if let Some(i) = first_of.get(&id[..last]) {
+                consider(*i, j);
             }
         }
     }
-    String::from("no matches found")
+
+    let (i, j) = best?;
+    let (_, common) = string_diff(ids[i], ids[j]);
+    Some((i, j, common))
+}
+
+// Characters past the end of the shorter string count as differences
+pub fn hamming(s1: &str, s2: &str) -> usize {
+    let (length1, length2) = (s1.chars().count(), s2.chars().count());
+    let length_difference = usize::max(length1, length2) - usize::min(length1, length2);
+    s1.chars().zip(s2.chars()).filter(|(x, y)| x != y).count() + length_difference
+}
+
+// the differences counted like hamming, and the characters both strings share
+pub fn string_diff(s1: &str, s2: &str) -> (usize, String) {
+    let mask = match_mask(s1, s2);
+    let length_difference = usize::max(s1.chars().count(), s2.chars().count()) - mask.len();
+    let differences = mask.iter().filter(|same| !**same).count() + length_difference;
+    (differences, common_chars(s1, &mask))
 }
 
-fn string_diff(s1: &str, s2: &str) -> (usize, String) {
-    let differences = s1.chars().zip(s2.chars()).filter(|(x, y)| x != y).count();
-    let commons: String = s1
-        .chars()
-        .zip(s2.chars())
-        .filter(|(x, y)| x == y)
+// the characters of s where the mask is true
+fn common_chars(s: &str, mask: &[bool]) -> String {
+    s.chars()
+        .zip(mask.iter())
+        .filter(|(_, same)| **same)
         .map(|(x, _)| x)
-        .collect();
-    (differences, commons)
+        .collect()
+}
+
+// true where both strings have the same character, as long as the shorter string
+pub fn match_mask(a: &str, b: &str) -> Vec<bool> {
+    a.chars().zip(b.chars()).map(|(x, y)| x == y).collect()
+}
+
+pub struct Day02;
+
+impl Day for Day02 {
+    fn part1(&self, input: &str) -> Result<String, AdventError> {
+        let ids: Vec<&str> = input.lines().collect();
+        Ok(checksum(&ids).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, AdventError> {
+        let ids: Vec<&str> = input.lines().collect();
+        correct_id(&ids)
+            .ok_or_else(|| AdventError::NoAnswer(String::from("no two ids differ by one letter")))
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::Day02;
+    use crate::day::Day;
+    use crate::error::AdventError;
+    use crate::input::read_input;
+    use crate::test_util::Seeded;
+    #[test]
+    fn test_day() {
+        let input = INPUT.join("\n");
+        assert_eq!(Ok(String::from("8610")), Day02.part1(&input));
+        assert_eq!(
+            Ok(String::from("iosnxmfkpabcjpdywvrtahluy")),
+            Day02.part2(&input)
+        );
+        assert_eq!(
+            Err(AdventError::NoAnswer(String::from(
+                "no two ids differ by one letter"
+            ))),
+            Day02.part2("abcde\nfghij")
+        );
+    }
+
     #[test]
     fn test_count() {
         let test_input = vec![
@@ -59,16 +276,234 @@ mod tests {
         assert_eq!(8610, super::checksum(&INPUT));
     }
 
+    #[test]
+    fn test_cluster_by_signature() {
+        let clusters = super::cluster_by_signature(&["abcde", "fghij", "edcba", "abcdd"]);
+        assert_eq!(3, clusters.len());
+        assert_eq!(vec![0, 2], clusters["abcde"]);
+        assert_eq!(vec![1], clusters["fghij"]);
+        assert_eq!(vec![3], clusters["abcdd"]);
+        assert!(super::cluster_by_signature(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_checksum_accumulator() {
+        let mut accumulator = super::ChecksumAccumulator::default();
+        assert_eq!(0, accumulator.finish());
+        for id in "abcdef bababc abbcde abcccd aabcdd abcdee ababab".split(' ') {
+            accumulator.feed(id);
+        }
+        assert_eq!(12, accumulator.finish());
+    }
+
+    #[test]
+    fn test_classify() {
+        let test_input = vec![
+            "abcdef", "bababc", "abbcde", "abcccd", "aabcdd", "abcdee", "ababab",
+        ];
+        let (doubles, triples) = super::classify(&test_input);
+        // bababc has two a's and three b's
+        assert_eq!(vec![1, 2, 4, 5], doubles);
+        assert_eq!(vec![1, 3, 6], triples);
+        assert_eq!(
+            super::checksum_for(&INPUT, &[2, 3]),
+            super::checksum(&INPUT)
+        );
+    }
+
+    #[test]
+    fn test_match_mask() {
+        assert_eq!(
+            vec![true, false, true, false, true],
+            super::match_mask("abcde", "axcye")
+        );
+        assert_eq!(vec![true, true], super::match_mask("ab", "abc"));
+        assert!(super::match_mask("", "abc").is_empty());
+        assert_eq!(
+            (2, String::from("ace")),
+            super::string_diff("abcde", "axcye")
+        );
+        assert_eq!((1, String::from("ab")), super::string_diff("ab", "abc"));
+    }
+
+    #[test]
+    fn test_owned_ids() {
+        let owned: Vec<String> = INPUT.iter().map(|id| id.to_string()).collect();
+        assert_eq!(8610, super::checksum(&owned));
+        assert_eq!(super::correct_id(&INPUT), super::correct_id(&owned));
+    }
+
+    #[test]
+    fn test_checksum_normalized() {
+        let lower = vec!["abab", "ccc"];
+        let mixed = vec!["ABab ", " cCc"];
+        assert_eq!(1, super::checksum(&lower));
+        assert_eq!(0, super::checksum(&mixed));
+        assert_eq!(1, super::checksum_normalized(&lower, true));
+        assert_eq!(1, super::checksum_normalized(&mixed, true));
+        assert_eq!(0, super::checksum_normalized(&mixed, false));
+        assert_eq!(1, super::checksum_normalized(&["abab\t", "ccc\n"], false));
+    }
+
+    #[test]
+    fn test_checksum_for() {
+        let test_input = vec![
+            "abcdef", "bababc", "abbcde", "abcccd", "aabcdd", "abcdee", "ababab",
+        ];
+        assert_eq!(12, super::checksum_for(&test_input, &[2, 3]));
+        // bababc has both a double and a triple, so it lands in both buckets
+        assert_eq!(1, super::checksum_for(&["bababc"], &[2, 3]));
+        assert_eq!(0, super::checksum_for(&test_input, &[2, 3, 4]));
+        assert_eq!(2, super::checksum_for(&["aaaab", "aabbbbc"], &[2, 4]));
+    }
+
+    #[test]
+    fn test_letter_frequencies() {
+        let frequencies = super::letter_frequencies("bababc");
+        assert_eq!(3, frequencies.len());
+        assert_eq!(2, frequencies[&'a']);
+        assert_eq!(3, frequencies[&'b']);
+        assert_eq!(1, frequencies[&'c']);
+    }
+
     #[test]
     fn test_correct_id() {
         let test_input = vec![
             "abcde", "fghij", "fguij", "klmno", "pqrst", "axcye", "wvxyz",
         ];
-        assert_eq!(String::from("fgij"), super::correct_id(&test_input));
+        assert_eq!(Some(String::from("fgij")), super::correct_id(&test_input));
         assert_eq!(
-            String::from("iosnxmfkpabcjpdywvrtahluy"),
+            Some(String::from("iosnxmfkpabcjpdywvrtahluy")),
             super::correct_id(&INPUT)
         );
+        assert_eq!(None, super::correct_id(&["abcde", "fghij"]));
+    }
+
+    #[test]
+    fn test_correct_id_with_index() {
+        let test_input = vec![
+            "abcde", "fghij", "fguij", "klmno", "pqrst", "axcye", "wvxyz",
+        ];
+        assert_eq!(
+            Some((String::from("fgij"), 2)),
+            super::correct_id_with_index(&test_input)
+        );
+        assert_eq!(
+            Some((String::from("bcd"), 0)),
+            super::correct_id_with_index(&["xbcd", "ybcd"])
+        );
+        assert_eq!(None, super::correct_id_with_index(&["abc", "xyz"]));
+        assert_eq!(
+            Some((String::from("abc"), 3)),
+            super::correct_id_with_index(&["abcd", "abc"])
+        );
+        assert_eq!(
+            Some((String::from("abc"), 3)),
+            super::correct_id_with_index(&["abc", "abcd"])
+        );
+        let (common, index) = super::correct_id_with_index(&INPUT).unwrap();
+        assert_eq!("iosnxmfkpabcjpdywvrtahluy", common);
+        assert_eq!(21, index);
+    }
+
+    #[test]
+    fn test_correct_id_pair() {
+        let test_input = vec![
+            "abcde", "fghij", "fguij", "klmno", "pqrst", "axcye", "wvxyz",
+        ];
+        assert_eq!(
+            Some((1, 2, String::from("fgij"))),
+            super::correct_id_pair(&test_input)
+        );
+        assert_eq!(None, super::correct_id_pair(&["abcde", "fghij"]));
+
+        // a trailing extra character is one difference, like in hamming
+        assert_eq!(
+            Some((0, 1, String::from("abcd"))),
+            super::correct_id_pair(&["abcd", "abcde"])
+        );
+        // the first pair in input order wins, even when a later pair differs in an
+        // earlier column
+        assert_eq!(
+            Some((0, 1, String::from("abcd"))),
+            super::correct_id_pair(&["abcde", "abcdx", "qqqqq", "zqqqq"])
+        );
+        assert_eq!(
+            Some((0, 2, String::from("ab"))),
+            super::correct_id_pair(&["abc", "xyz", "ab", "abc"])
+        );
+        assert_eq!(
+            Some((0, 1, String::from(""))),
+            super::correct_id_pair(&["", "a"])
+        );
+        assert_eq!(None, super::correct_id_pair(&["ab", "ab", "ba"]));
+
+        // every subset of the short IDs over "ab", in a few orders, gives the same first
+        // pair as comparing every pair with hamming
+        let all: Vec<String> = (0..=3)
+            .flat_map(|len| {
+                (0..1 << len).map(move |bits: u32| {
+                    (0..len)
+                        .map(|k| if bits >> k & 1 == 1 { 'b' } else { 'a' })
+                        .collect()
+                })
+            })
+            .collect();
+        for step in 1..=5 {
+            for start in 0..all.len() {
+                let ids: Vec<&str> = (0..all.len())
+                    .step_by(step)
+                    .map(|k| all[(start + k * 7) % all.len()].as_str())
+                    .collect();
+                let expected = super::pairs_within(&ids, 1)
+                    .into_iter()
+                    .find(|(i, j)| super::hamming(ids[*i], ids[*j]) == 1);
+                assert_eq!(
+                    expected,
+                    super::correct_id_pair(&ids).map(|(i, j, _)| (i, j))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_correct_id_large_input() {
+        // far too many pseudo-random IDs to compare every pair, with one planted near-duplicate
+        let mut seeded = Seeded::new(42);
+        let mut ids: Vec<String> = (0..20000)
+            .map(|_| {
+                (0..26)
+                    .map(|_| (b'a' + seeded.below(26) as u8) as char)
+                    .collect()
+            })
+            .collect();
+        let mut twin: Vec<char> = ids[1234].chars().collect();
+        twin[17] = if twin[17] == 'z' { 'a' } else { 'z' };
+        ids.push(twin.iter().collect());
+        let ids: Vec<&str> = ids.iter().map(|id| id.as_str()).collect();
+
+        let (i, j, common) = super::correct_id_pair(&ids).unwrap();
+        assert_eq!((1234, 20000), (i, j));
+        assert_eq!(25, common.len());
+        assert_eq!(Some(common), super::correct_id(&ids));
+    }
+
+    #[test]
+    fn test_pairs_within() {
+        let test_input = vec![
+            "abcde", "fghij", "fguij", "klmno", "pqrst", "axcye", "wvxyz",
+        ];
+        assert_eq!(vec![(0, 5), (1, 2)], super::pairs_within(&test_input, 2));
+        assert_eq!(vec![(1, 2)], super::pairs_within(&test_input, 1));
+        assert!(super::pairs_within(&test_input, 0).is_empty());
+    }
+
+    #[test]
+    fn test_hamming() {
+        assert_eq!(2, super::hamming("abcde", "axcye"));
+        assert_eq!(0, super::hamming("abcde", "abcde"));
+        assert_eq!(5, super::hamming("abcde", "fghij"));
+        assert_eq!(1, super::hamming("abc", "abcd"));
     }
 
     #[test]
@@ -86,260 +521,12 @@ mod tests {
             super::string_diff("fghij", "fguij")
         );
         assert_eq!((5, String::from("")), super::string_diff("abcde", "fghij"));
+        assert_eq!((1, String::from("abc")), super::string_diff("abc", "abcd"));
+        assert_eq!((1, String::from("abc")), super::string_diff("abcd", "abc"));
+        assert_eq!((3, String::from("")), super::string_diff("", "abc"));
     }
 
     lazy_static! {
-        static ref INPUT: Vec<&'static str> = vec![
-            "uosnxmfkezbojfdgwvrtaqhluy",
-            "iosnxmfkazbcopdgnvrtaqhluy",
-            "ioanxmfkezbcjpdgwvrjaohluy",
-            "uosnxmfkezbcjpjgwvrtaqhlut",
-            "imsnxmfkezbcjpugwvataqhluy",
-            "ioenxmfkezbcjpdgwvrraqhluz",
-            "iosnxmfkezbcjpdgevitnqhluy",
-            "iosnxmfkezcccpdgcvrtaqhluy",
-            "loinxmfkezbcjpdgwvrtaqhluu",
-            "iosnlmfkezbczndgwvrtaqhluy",
-            "iosnxmfkezbcjpdgwvrifghluy",
-            "iosnuhfkezbcjpugwvrtaqhluy",
-            "iosnxmfkezbcwpdgwvrtaihlgy",
-            "iosnxzfwuzbcjpdgwvrtaqhluy",
-            "hosnxmfjizbcjpdgwvrtaqhluy",
-            "iornxmfktzbcjpdgwvrtaqhluo",
-            "nosnxmfkdzbcjpdgwvrtaqhlwy",
-            "iosnxmfkezbcjpdgwvrtaktluq",
-            "ioszxmlkezbcjvdgwvrtaqhluy",
-            "ionnxmfkezbcfpdgwvbtaqhluy",
-            "iosnxmfkezrcjedgwvrtaqhluq",
-            "irsnxmfkezbcjpdqwvrtafhluy",
-            "ioshxmzkezbccpdgwvrtaqhluy",
-            "iosnxmfkezbrjpdgwvothqhluy",
-            "bosnxmfkezbcbpdgwvrtnqhluy",
-            "iosnomfkszbcjpcgwvrtaqhluy",
-            "iosnxmflezbcjpdgwvrtaqmuuy",
-            "iobnxmfkezbcjpdgxvrtaqfluy",
-            "ioenxmfvezbcjgdgwvrtaqhluy",
-            "iosnxmfkekbcjprgwvrtaqhlty",
-            "iosnumfkezbcjpmgwvrtaqhlmy",
-            "ionnxufkezbcjpdgwvrqaqhluy",
-            "tosnxmfbezbcjpdghvrtaqhluy",
-            "iosnxmfktzbcjpogwmrtaqhluy",
-            "iosnamfkezbjjpdgtvrtaqhluy",
-            "iosnemfkezmcjpdgwvrtaqhlry",
-            "losnxmfkezbcjpdxwvrtaqsluy",
-            "fomnxmekezbcjpdgwvrtaqhluy",
-            "rosnxmfkezbcjzdcwvrtaqhluy",
-            "iosngmrkezbcjpdgwvrtaqhduy",
-            "iosnxmaaebbcjpdgwvrtaqhluy",
-            "xosnxmfkezbcjpdgwvrmrqhluy",
-            "iosnxmfkgzbujpdgwhrtaqhluy",
-            "iosnxmekecbcjpmgwvrtaqhluy",
-            "mesnxmfdezbcjpdgwvrtaqhluy",
-            "insnxmbkezbcjpdgwvrtgqhluy",
-            "iosyxmfkezbcjpdgwirtavhluy",
-            "iosnxmfkezbcjpdgwlvtjqhluy",
-            "iosnxmtkezbcjpdgwvjtaqhlut",
-            "iosnxmfkezbsjpdhwvrtaqaluy",
-            "iosnumfkezbcjpfgwvrtaqhlfy",
-            "iosnxmekezbcdpxgwvrtaqhluy",
-            "iosnfmfkezbcjpdgavctaqhluy",
-            "iosnxmfkezvcjpdgfvrtamhluy",
-            "iovnxmfkezbcjpdgzvrtaqhzuy",
-            "iosnbmfkuzbcjpdgwvrtaqhlux",
-            "iosnxmfkezbcjpdgwvftauhluc",
-            "iosmbmfkezbcmpdgwvrtaqhluy",
-            "ifsnxmfvezbcjpdgwvrwaqhluy",
-            "iosnxmfkezfcjpdgwvrmaqhyuy",
-            "iospxmfkezbcjpdkwvytaqhluy",
-            "issnxmfkyzbcjpdgwyrtaqhluy",
-            "iosnxmfkezbcjpdbwvrtjqhluz",
-            "iosnxmfkwzbcjpdgfvrtajhluy",
-            "iosnxmfkezbcjndgwvrnaqxluy",
-            "iosnxmfkezbcjpdgwvltawwluy",
-            "iosnxmfkezbcjpdguvrtwqhtuy",
-            "iornxmfkezbcjpdgwertaihluy",
-            "iofdxmokezbcjpdgwvrtaqhluy",
-            "iosnxmfkezbcjpdgwgrtiqdluy",
-            "iosnxmfkenbcjpdgwqrtiqhluy",
-            "iosnxmfkezbcjpugwvotcqhluy",
-            "iksnxmfkezbcjfdgqvrtaqhluy",
-            "iasnxmfkezbcjpdgwvrtaqheuo",
-            "iosnxmfkehbcipdgwvrtaqtluy",
-            "iosnxifkezbajpdgwvrtaahluy",
-            "iosnxmpkezbccpdguvrtaqhluy",
-            "ioinxnfkezbcjpdgwvgtaqhluy",
-            "gosnxmfkezbcjpdgwvrtvqkluy",
-            "iolnxmfcezbcjpdgwvrtaqhlgy",
-            "iosnxmfkezbcppdgwortjqhluy",
-            "iesnxafkezbcjpdgwvrtayhluy",
-            "iqsnxmfxazbcjpdgwvrtaqhluy",
-            "cosnxmfkezbcjpdgwvrtkahluy",
-            "ioenxmfkezbcjpdgwvrtzqyluy",
-            "iosnxmhkwzbcjpdgwvrtabhluy",
-            "iosnxmfkezbcjpdtwvrhaqiluy",
-            "iisnxmfkezbcvpdwwvrtaqhluy",
-            "iosnsmfkeobcjpdgfvrtaqhluy",
-            "iwsnxmfkfzbcjpugwvrtaqhluy",
-            "iosnxmflezbcjpdgwvrtaspluy",
-            "gosnimfkezbcjpdgwvrtjqhluy",
-            "iosnxmfkfibcjmdgwvrtaqhluy",
-            "iosnxmfkpzbcjpdgwvitaqhwuy",
-            "ionnxmfkerbcjpjgwvrtaqhluy",
-            "iosnxmfkezecjgdgwvrtaqhljy",
-            "iosnxufkezbcjpdguvltaqhluy",
-            "vosnzmfkezbcjpdvwvrtaqhluy",
-            "iolnxmfkecbcjpdgwvrtaqpluy",
-            "iosnxmfkezbcjpdgwortaqhouw",
-            "iomnxmfkezbckpdgwvrtaqhluu",
-            "iopnymfkezbchpdgwvrtaqhluy",
-            "iosnxmfkezhcjpdguvrtaqhnuy",
-            "iosfxmfkezecjpdgyvrtaqhluy",
-            "iopnxmfkgzbcjpdgwvbtaqhluy",
-            "tosnxmffezbcjpdgwvttaqhluy",
-            "iosnxmfkpabcjpdywvrtaqhluy",
-            "iosrxmfkekbcjpdgwvrtaqrluy",
-            "iosnxmokezbcjpdjwvrtaxhluy",
-            "iolnxmfkezbccpdgwvetaqhluy",
-            "iosnxmfketecjpdgwvrtaqnluy",
-            "iosnxmfkxzbtjpdgwvroaqhluy",
-            "ioinxmfkezbcjpdqwvrtjqhluy",
-            "iosnxmfkqzbcjpdgwvrtaqzluz",
-            "iosnxmfklzbcjpdgwwrtaqhluh",
-            "iosnxmfkezbcjpdtwvrtmqhlpy",
-            "iosnomfqezgcjpdgwvrtaqhluy",
-            "iosnxmfkezbcjodgwvutaqhduy",
-            "iosnxmfkezbcjppgwertaqhluu",
-            "iosnxmfkezbcjqdggvrtaqhluw",
-            "iosnxmvkezbcjpdgwvrtlqfluy",
-            "icsnwmfkezbcjpdiwvrtaqhluy",
-            "iosnxxbkezbhjpdgwvrtaqhluy",
-            "ioknxmfkezacjpdgwvrtaqhliy",
-            "iosgxmfkezbcjpdgevrtpqhluy",
-            "iosnxmfkezbejpdgwlrtaqhldy",
-            "iosnxyfkezbcjpdowvrtaqhlur",
-            "iosnxmfkezbcjpnjwvrtaqhlvy",
-            "iosnxglkezbcjpdvwvrtaqhluy",
-            "iosnxmpkezbcjpdgwvrtxqhlub",
-            "iosnxsfwezbcjpdgwmrtaqhluy",
-            "aosnxmfkezbcjpdgwvrtaqhpwy",
-            "iopnxmqkkzbcjpdgwvrtaqhluy",
-            "iosnxmfkewbcfpdgwvrtaqmluy",
-            "iosnxmfkekbcjpdgwvltawhluy",
-            "iosnxmfmezbcjpdgwvitaqtluy",
-            "iosnomfkezbcjpggwvrtaqhlly",
-            "iobnkmfkezbcjpdywvrtaqhluy",
-            "yosnxmfkezbcjydgwvrtarhluy",
-            "iosnxifkezbckpdgyvrtaqhluy",
-            "iornxmfkezbcjpduwvreaqhluy",
-            "ivsfxmfjezbcjpdgwvrtaqhluy",
-            "iosnxmfkezbcspdgwartaqhlui",
-            "iosnxmfkezbcjpdgasstaqhluy",
-            "iosnxmfkezbajpdgwvrtaqmlux",
-            "gzsnxmfkezxcjpdgwvrtaqhluy",
-            "iosnxmikczbcjpdgwvrtyqhluy",
-            "iosnxmgkezbcjvdgwdrtaqhluy",
-            "iosnxpfkezbcjpdgwvrbachluy",
-            "igsnxmfkezbcjpdgwkrtaqtluy",
-            "posnxmfkfzbcjpdgwvrpaqhluy",
-            "iosnxmfkezbhjtdgwvrtaqhsuy",
-            "iosfxmfkezbcjpdwwvrtaqvluy",
-            "iosnxmfkehecjpdgwvrtaqoluy",
-            "iasnxmfkezbckpdgfvrtaqhluy",
-            "iosnxmfkezbwjpdggvrtaqhlmy",
-            "iosnxmfkezbcjpdgwvrkaqhbun",
-            "iosnxmikezbcjpdgwvrtaqhlnt",
-            "iosnxmfiazbcjpdgwvetaqhluy",
-            "iosnxmfkczbcjpfgwvrnaqhluy",
-            "iosnxmfkezkcjpdgsvrqaqhluy",
-            "iosnxmfkezbcspdgwvrtaqhxuc",
-            "iosnxmfdezbcjpdgwzrteqhluy",
-            "qosnxmrkezbcjpdgwvrtaqhlpy",
-            "iosnxmfkpabcjpdywvrtawhluy",
-            "ojsnxmfkezbcjpdgwvrtiqhluy",
-            "iosrxmfkezbcjpdgdvrtaqhlmy",
-            "iosnxmfkezbcnqdgwvrtayhluy",
-            "ionnxmfkezbcjpdgwvrsaehluy",
-            "iosnxmfkezbcjpdgwvrtmqhpuk",
-            "ifsnxmfkezbcjpdpwvrtaqhluf",
-            "insnxmfkezbcjpdgwrrtaqhmuy",
-            "iosnxmfxezbcjpdjwvrfaqhluy",
-            "iojnxmbkezccjpdgwvrtaqhluy",
-            "iosnomlkezbcjpdgwvotaqhluy",
-            "iosnamfkezbcjpdgwvrhqqhluy",
-            "iksnxmfkezbbjrdgwvrtaqhluy",
-            "iosnfmfkezbcjpdgwvrtaqhyay",
-            "iosnxmzkezbcjpdayvrtaqhluy",
-            "iosnxmfkezbcwpdgwbrtaqhlut",
-            "iosnxmfkezccjpdgivrtaqhbuy",
-            "iosuxmfkezbcjgdgwvrtaqhvuy",
-            "ipsnxmfkezbcjpaiwvrtaqhluy",
-            "iisnxmfkezbcjpdgpvrtaqqluy",
-            "ihsnxmfkezbcspdgwvrtahhluy",
-            "imsnxmfkezbcjpdgwvrtaqhkly",
-            "josnxmfkezbpjpdgwvttaqhluy",
-            "bosnxyfkezmcjpdgwvrtaqhluy",
-            "iosnxmfkezbcjpkgwvrtkqhjuy",
-            "iosnxmfkezbcjpdgwfrgaqfluy",
-            "rosnxmfkqzbcjpdgwvxtaqhluy",
-            "iosnxmfkezbcjpdgwlrwaqhluu",
-            "yysnxmfkezbcjpdgwvrtaxhluy",
-            "iosnxmpkezbcjldgwvrtaqoluy",
-            "gosnxmfkezrcjpdgwvrtarhluy",
-            "iosnxmfrezbcjrdmwvrtaqhluy",
-            "iosnxmfkekbcjpdgpvrtaqhyuy",
-            "iosbemfkezbcjpdgwdrtaqhluy",
-            "iosnxmfkezucjpdgwvatamhluy",
-            "ioanfmfkwzbcjpdgwvrtaqhluy",
-            "iosnxphkezbcjpdgwvrtaqhlly",
-            "ioynxmfkezbcjvbgwvrtaqhluy",
-            "iosnnmfkwzbcjpdgwvrtaqbluy",
-            "iosnxmfjezbcjpkgwtrtaqhluy",
-            "iosexmfkezbcjpdgwvrtmshluy",
-            "irsnxmwkezbcjpdgwvotaqhluy",
-            "iosnxmfkezpcjpdgwvrlaqkluy",
-            "iosnxmfkezbcjpwgwvroaqkluy",
-            "iosnxmfkizbcjpdgwvrtaqxlay",
-            "ioszxmfkezbcjpdgwertrqhluy",
-            "iosnxmfkczscjpdgwvrtcqhluy",
-            "iosnxmfkedbcjpdgwirtaqhliy",
-            "iosgxmfpezbcjpdgwvvtaqhluy",
-            "iownxmfiezbcjpdgwvrtajhluy",
-            "iosnxmfkezbejudgwvrqaqhluy",
-            "iomnpmfkezbcjpdgwvwtaqhluy",
-            "ioshxmfkecbcjpdgwfrtaqhluy",
-            "iosnxmfkezmcjpdgwzrtaqkluy",
-            "iownxdfkezdcjpdgwvrtaqhluy",
-            "iosnxmfjezbcjpdgwrotaqhluy",
-            "roknxmfkezbcjpdgwxrtaqhluy",
-            "iosnxmfkeibcjpdgovrtaqhloy",
-            "ifsnxmfkelbcjpdgwvrcaqhluy",
-            "iosnamfuezbcjpdwwvrtaqhluy",
-            "rssnxmfkeebcjpdgwvrtaqhluy",
-            "iosnomfkjzbcjpdgwvrtaqhlun",
-            "iosnxmfuezbcjpdgwfjtaqhluy",
-            "iosnxzfkezbcjpdewvrtaqhlfy",
-            "iosnxmfkezbcjpdgwvrtzqhlgr",
-            "iosixmfkezbcjpdgwvrkaqhlut",
-            "issnxmfkezbdjpdpwvrtaqhluy",
-            "iosnxmfrezbcjpdgwkrtaghluy",
-            "iysnxmfkezbcjpdgwrrtmqhluy",
-            "iosoxmfkezbcjpdgwjrtaqhlua",
-            "eosnxmfkezvcjpdgwvztaqhluy",
-            "iosmxmckezbcjpdgwvrtaqhlay",
-            "iosnxmfkezbcjodgwvrtaqhlma",
-            "josnxwftezbcjpdgwvrtaqhluy",
-            "iosnxjfkepbcjpdgwvrtaqhlsy",
-            "iosnnmfkezbcjpdgwvriaqhnuy",
-            "iosnxofkezbcupdgwvrtayhluy",
-            "iosnxmfkezbcjpddwvroaqhluz",
-            "iosnomfkezbcapdhwvrtaqhluy",
-            "iosixmfkezycjpdgwvrtaqhruy",
-            "iosnwefkezbcjpdgwvrtaqcluy",
-            "iosnxmfkvzbcbpdgwvrhaqhluy",
-            "insnxmfkezbczpdgwvrtajhluy",
-            "iosnxrfkelbcjpdgwvrtaqhluf",
-            "iosnxmfkezbcjpdgwsrtaqhzud",
-            "iosnxmfyvzbcjpdgwyrtaqhluy"
-        ];
+        static ref INPUT: Vec<String> = read_input(2).unwrap().lines().map(String::from).collect();
     }
 }
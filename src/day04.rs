@@ -1,41 +1,72 @@
+use std::cmp::Reverse;
 use std::collections::HashMap;
+use std::io::BufRead;
 use std::str::FromStr;
 
 use chrono::{NaiveDateTime, Timelike}; // at the North Pole, there are no time zones. WeSmart.
 use regex::Regex;
 
+use crate::day::Day;
+use crate::error::AdventError;
+
 #[derive(Debug)]
-struct Nap {
-    start: NaiveDateTime,
-    end: NaiveDateTime,
+pub struct Nap {
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
 }
 
 impl Nap {
-    fn duration(&self) -> i64 {
+    pub fn duration(&self) -> i64 {
         (self.end - self.start).num_minutes()
     }
 }
 
-enum Record {
+// turned into an AdventError before it leaves this module
+#[derive(Debug, PartialEq)]
+pub(crate) struct ParseError {
+    pub(crate) line: String,
+    // None if the line couldn't be parsed at all
+    pub(crate) reason: Option<&'static str>,
+}
+
+impl ParseError {
+    fn new(line: &str) -> ParseError {
+        ParseError {
+            line: line.to_string(),
+            reason: None,
+        }
+    }
+
+    fn with_reason(line: &str, reason: &'static str) -> ParseError {
+        ParseError {
+            line: line.to_string(),
+            reason: Some(reason),
+        }
+    }
+}
+
+const MINUTES_IN_DAY: u32 = 24 * 60;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Record {
     GuardChange(i32),
     NapStart(NaiveDateTime),
     NapEnd(NaiveDateTime),
 }
 
 impl FromStr for Record {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Record, ()> {
+    type Err = AdventError;
+    fn from_str(s: &str) -> Result<Record, AdventError> {
         if s.contains("Guard") {
             lazy_static! {
-                static ref RE: Regex = Regex::new("Guard #(?P<guard>[0-9]*)").unwrap();
+                static ref RE: Regex = Regex::new("Guard #(?P<guard>[0-9]+)").unwrap();
             }
-            let caps = RE.captures(s).unwrap();
-            let guard: i32 = i32::from_str(&caps["guard"]).unwrap();
+            let caps = RE.captures(s).ok_or_else(|| ParseError::new(s))?;
+            let guard = i32::from_str(&caps["guard"]).map_err(|_| ParseError::new(s))?;
             return Ok(Record::GuardChange(guard));
         }
 
-        let time_str: String = s.chars().skip(1).take(16).collect();
-        let time = NaiveDateTime::parse_from_str(&time_str, "%Y-%m-%d %H:%M").unwrap();
+        let time = timestamp(s)?;
         if s.contains("falls asleep") {
             return Ok(Record::NapStart(time));
         }
@@ -43,110 +74,312 @@ impl FromStr for Record {
         if s.contains("wakes up") {
             return Ok(Record::NapEnd(time));
         }
-        Err(())
+        Err(ParseError::new(s).into())
     }
 }
 
 #[allow(dead_code)]
 fn most_asleep_minute(guard_naps: &HashMap<i32, Vec<Nap>>) -> i32 {
-    let mut minutes = HashMap::new();
-    for i in 0..60 {
-        minutes.insert(i, (0, 0));
-    }
-    for (guard, naps) in guard_naps {
-        let (minute, times) = minute_most_often_asleep(&naps);
-        let (_, worst_times) = &minutes[&minute];
-        if times > *worst_times {
-            minutes.insert(minute, (*guard, times));
-        }
-    }
+    let (guard, minute) = strategy_two(guard_naps);
+    guard * minute as i32
+}
 
-    let mut high_naps = 0;
-    let mut worst_minute = 0;
-    let mut worst_guard = 0;
-    for (minute, (guard, times)) in minutes {
-        if times > high_naps {
-            high_naps = times;
-            worst_minute = minute;
-            worst_guard = guard;
+// the guard most frequently asleep on the same minute, and that minute
+// ties go to the earlier minute, then the smaller guard id
+pub fn strategy_two(guard_naps: &HashMap<i32, Vec<Nap>>) -> (i32, u32) {
+    guard_minute_counts(guard_naps)
+        .into_iter()
+        .max_by_key(|((guard, minute), times)| (*times, Reverse(*minute), Reverse(*guard)))
+        .map(|(guard_minute, _)| guard_minute)
+        .unwrap_or((0, 0))
+}
+
+// how many times each guard was asleep on each minute of the day
+pub fn guard_minute_counts(guard_naps: &HashMap<i32, Vec<Nap>>) -> HashMap<(i32, u32), u32> {
+    let mut counts = HashMap::new();
+    for (guard, naps) in guard_naps {
+        for minute in naps.iter().flat_map(nap_minutes) {
+            counts.entry((*guard, minute))
+                .and_modify(|n| *n += 1)
+                .or_insert(1);
         }
     }
-    worst_minute * worst_guard as i32
+    counts
 }
 
 #[allow(dead_code)]
 fn most_asleep_guard(guard_naps: &HashMap<i32, Vec<Nap>>) -> i32 {
+    let (guard, minute) = strategy_one(guard_naps);
+    guard * minute as i32
+}
+
+// the guard who slept the most in total, and the minute they were most often asleep
+pub fn strategy_one(guard_naps: &HashMap<i32, Vec<Nap>>) -> (i32, u32) {
     let mut worst_guard = -1;
     let mut minutes_slept = 0;
     let mut worst_minute = 0;
-    for (guard, naps) in guard_naps {
-        let total_nap_time: i64 = naps.iter().map(|x| x.duration()).sum();
+    for (guard, total_nap_time) in minutes_asleep_by_guard(guard_naps) {
         if total_nap_time > minutes_slept {
             minutes_slept = total_nap_time;
-            worst_guard = *guard;
-            let (temp, _) = minute_most_often_asleep(&naps);
+            worst_guard = guard;
+            let (temp, _) = minute_most_often_asleep(&guard_naps[&guard]);
             worst_minute = temp;
         }
     }
-    worst_guard * worst_minute as i32
+    (worst_guard, worst_minute)
 }
 
-fn minute_most_often_asleep(naps: &[Nap]) -> (i32, i32) {
-    let mut minutes = HashMap::new();
-    for i in 0..60 {
-        minutes.insert(i, 0);
-    }
-    for nap in naps {
-        for minute in nap.start.time().minute()..nap.end.time().minute() {
-            minutes.entry(minute)
-                .and_modify(|cur| *cur = *cur + 1);
-        }
-    }
+pub fn minutes_asleep_by_guard(guard_naps: &HashMap<i32, Vec<Nap>>) -> HashMap<i32, i64> {
+    guard_naps
+        .iter()
+        .map(|(guard, naps)| (*guard, naps.iter().map(|x| x.duration()).sum()))
+        .collect()
+}
+
+fn minute_most_often_asleep(naps: &[Nap]) -> (u32, u32) {
     let mut high_naps = 0;
     let mut high_minute = 0;
-    for (minute, naps) in minutes {
-        if naps > high_naps {
-            high_naps = naps;
-            high_minute = minute;
+    for (minute, naps) in day_histogram(naps).iter().enumerate() {
+        if *naps > high_naps {
+            high_naps = *naps;
+            high_minute = minute as u32;
         }
     }
-    (high_minute as i32, high_naps)
+    (high_minute, high_naps)
 }
 
-#[allow(dead_code)]
-fn process_logs(s: &str) -> HashMap<i32, Vec<Nap>> {
-    let mut lines: Vec<&str> = s.lines().collect();
-    lines.sort();
-    let mut guard_naps: HashMap<i32, Vec<Nap>> = HashMap::new();
-    let mut current_guard: Option<i32> = None;
-    let mut start_time: Option<NaiveDateTime> = None;
-    for line in lines.iter() {
-        match Record::from_str(line) {
-            Ok(Record::GuardChange(guard)) => current_guard = Some(guard),
-            Ok(Record::NapStart(time)) => start_time = Some(time),
-            Ok(Record::NapEnd(time)) => {
-                let nap = Nap {
-                    start: start_time.unwrap(),
-                    end: time,
-                };
-                let guard = current_guard.unwrap();
-                guard_naps.entry(guard)
+// how many times each minute of the hour was slept through, whatever the hour
+pub fn minute_histogram(naps: &[Nap]) -> [u32; 60] {
+    let mut minutes = [0; 60];
+    for (minute, naps) in day_histogram(naps).iter().enumerate() {
+        minutes[minute % 60] += naps;
+    }
+    minutes
+}
+
+// how many times each minute of the day was slept through
+pub fn day_histogram(naps: &[Nap]) -> Vec<u32> {
+    let mut minutes = vec![0; MINUTES_IN_DAY as usize];
+    for minute in naps.iter().flat_map(nap_minutes) {
+        minutes[minute as usize] += 1;
+    }
+    minutes
+}
+
+// the minutes of the day slept through in one nap
+// walks the elapsed time so naps running past midnight wrap around to minute 0
+fn nap_minutes(nap: &Nap) -> impl Iterator<Item = u32> {
+    let start = nap.start.time().hour() * 60 + nap.start.time().minute();
+    (0..nap.duration()).map(move |elapsed| (start + elapsed as u32) % MINUTES_IN_DAY)
+}
+
+// one row per nap, ordered by when the nap started
+pub fn to_csv(guard_naps: &HashMap<i32, Vec<Nap>>) -> String {
+    let mut naps: Vec<(i32, &Nap)> = guard_naps
+        .iter()
+        .flat_map(|(guard, naps)| naps.iter().map(move |nap| (*guard, nap)))
+        .collect();
+    naps.sort_by_key(|(_, nap)| nap.start);
+
+    let mut result = String::from("guard_id,start,end,duration_minutes\n");
+    for (guard, nap) in naps {
+        result.push_str(&format!(
+            "{},{},{},{}\n",
+            guard,
+            nap.start.format("%Y-%m-%d %H:%M"),
+            nap.end.format("%Y-%m-%d %H:%M"),
+            nap.duration()
+        ));
+    }
+    result
+}
+
+fn timestamp(s: &str) -> Result<NaiveDateTime, ParseError> {
+    lazy_static! {
+        static ref RE: Regex =
+            Regex::new("(?P<time>[0-9]{4}-[0-9]{2}-[0-9]{2} [0-9]{2}:[0-9]{2})").unwrap();
+    }
+    let caps = RE.captures(s).ok_or_else(|| ParseError::new(s))?;
+    NaiveDateTime::parse_from_str(&caps["time"], "%Y-%m-%d %H:%M").map_err(|_| ParseError::new(s))
+}
+
+pub fn process_logs(s: &str) -> Result<HashMap<i32, Vec<Nap>>, AdventError> {
+    Ok(build_naps(&sorted_records(s.lines())?)?)
+}
+
+pub fn process_log_lines<I: IntoIterator<Item = String>>(
+    lines: I,
+) -> Result<HashMap<i32, Vec<Nap>>, AdventError> {
+    let lines: Vec<String> = lines.into_iter().collect();
+    Ok(build_naps(&sorted_records(
+        lines.iter().map(|line| line.as_str()),
+    )?)?)
+}
+
+// the records still have to be sorted before naps can be paired, so every line is kept
+pub fn process_logs_reader<R: BufRead>(r: R) -> Result<HashMap<i32, Vec<Nap>>, AdventError> {
+    let lines = r.lines().collect::<Result<Vec<String>, _>>()?;
+    process_log_lines(lines)
+}
+
+// sorts on the parsed timestamp, so the lines can arrive in any order
+// blank lines are skipped, however the logs are read
+fn sorted_records<'a, I: IntoIterator<Item = &'a str>>(
+    lines: I,
+) -> Result<Vec<(NaiveDateTime, Record, &'a str)>, AdventError> {
+    let mut records = vec![];
+    for line in lines.into_iter().filter(|line| !line.trim().is_empty()) {
+        records.push((timestamp(line)?, Record::from_str(line)?, line));
+    }
+    records.sort_by_key(|(time, _, _)| *time);
+    Ok(records)
+}
+
+fn build_naps(
+    records: &[(NaiveDateTime, Record, &str)],
+) -> Result<HashMap<i32, Vec<Nap>>, ParseError> {
+    let mut builder = LogBuilder::new();
+    for (time, record, line) in records.iter() {
+        builder.ingest(*time, record, line)?;
+    }
+    Ok(builder.finish())
+}
+
+// builds the schedule one record at a time, so the records have to arrive in order
+#[derive(Debug, Default)]
+pub struct LogBuilder {
+    current_guard: Option<i32>,
+    start_time: Option<NaiveDateTime>,
+    last_time: Option<NaiveDateTime>,
+    guard_naps: HashMap<i32, Vec<Nap>>,
+}
+
+impl LogBuilder {
+    pub fn new() -> LogBuilder {
+        LogBuilder::default()
+    }
+
+    pub fn push(&mut self, line: &str) -> Result<(), AdventError> {
+        if line.trim().is_empty() {
+            return Ok(());
+        }
+        let time = timestamp(line)?;
+        let record = Record::from_str(line)?;
+        Ok(self.ingest(time, &record, line)?)
+    }
+
+    pub fn finish(self) -> HashMap<i32, Vec<Nap>> {
+        self.guard_naps
+    }
+
+    fn ingest(
+        &mut self,
+        time: NaiveDateTime,
+        record: &Record,
+        line: &str,
+    ) -> Result<(), ParseError> {
+        if self.last_time.is_some_and(|last| time < last) {
+            return Err(ParseError::with_reason(line, "record out of order"));
+        }
+        self.last_time = Some(time);
+        match *record {
+            Record::GuardChange(guard) => {
+                // guards who stay awake all shift are listed too, with no naps
+                self.guard_naps.entry(guard)
+                    .or_default();
+                self.current_guard = Some(guard);
+            }
+            Record::NapStart(time) => {
+                if self.start_time.is_some() {
+                    return Err(ParseError::with_reason(line, "fell asleep while asleep"));
+                }
+                self.start_time = Some(time);
+            }
+            Record::NapEnd(time) => {
+                let start = self
+                    .start_time
+                    .ok_or_else(|| ParseError::with_reason(line, "woke up while awake"))?;
+                let guard = self
+                    .current_guard
+                    .ok_or_else(|| ParseError::with_reason(line, "nap before any guard"))?;
+                // a nap has to last at least a minute, or its duration would be
+                // zero or negative and throw off the minutes asleep
+                if time <= start {
+                    return Err(ParseError::with_reason(
+                        line,
+                        "woke up before falling asleep",
+                    ));
+                }
+                let nap = Nap { start, end: time };
+                self.guard_naps.entry(guard)
                     .or_default()
                     .push(nap);
-                start_time = None;
+                self.start_time = None;
             }
-            Err(()) => panic!("error while parsing"),
         }
+        Ok(())
     }
-    guard_naps
+}
+
+pub struct Day04;
+
+impl Day for Day04 {
+    fn part1(&self, input: &str) -> Result<String, AdventError> {
+        let logs = guard_logs(input)?;
+        let (guard, minute) = strategy_one(&logs);
+        Ok((guard * minute as i32).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, AdventError> {
+        let logs = guard_logs(input)?;
+        let (guard, minute) = strategy_two(&logs);
+        Ok((guard * minute as i32).to_string())
+    }
+}
+
+// with no guards there's no one to pick, so no answer either
+fn guard_logs(input: &str) -> Result<HashMap<i32, Vec<Nap>>, AdventError> {
+    let logs = process_logs(input)?;
+    if logs.is_empty() {
+        return Err(AdventError::NoAnswer(String::from("no guards in the logs")));
+    }
+    Ok(logs)
 }
 
 #[cfg(test)]
 mod tests {
+    use super::Day04;
+    use crate::day::Day;
+    use crate::error::AdventError;
+    use crate::input::read_input;
     use chrono::NaiveDateTime;
+    use std::collections::HashMap;
+    use std::io::Cursor;
+    #[test]
+    fn test_day() {
+        assert_eq!(Ok(String::from("240")), Day04.part1(TEST_INPUT));
+        assert_eq!(Ok(String::from("4455")), Day04.part2(TEST_INPUT));
+        assert_eq!(Ok(String::from("102688")), Day04.part1(&REAL_INPUT));
+        assert_eq!(Ok(String::from("56901")), Day04.part2(&REAL_INPUT));
+        assert_eq!(
+            Err(AdventError::Parse(String::from("garbage"))),
+            Day04.part1("garbage")
+        );
+        assert_eq!(
+            Err(AdventError::Invalid {
+                line: String::from("[1518-11-01 00:05] wakes up"),
+                reason: "woke up while awake"
+            }),
+            Day04.part2("[1518-11-01 00:00] Guard #10 begins shift\n[1518-11-01 00:05] wakes up")
+        );
+        let no_guards = Err(AdventError::NoAnswer(String::from("no guards in the logs")));
+        assert_eq!(no_guards, Day04.part1(""));
+        assert_eq!(no_guards, Day04.part2("\n"));
+    }
+
     #[test]
     fn test_process_logs() {
-        let logs = super::process_logs(TEST_INPUT);
+        let logs = super::process_logs(TEST_INPUT).unwrap();
         assert_eq!(2, logs.len());
         assert_eq!(3, logs[&10].len());
         assert_eq!(3, logs[&99].len());
@@ -156,26 +389,301 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_process_log_lines() {
+        let mut lines: Vec<String> = TEST_INPUT.lines().map(String::from).collect();
+        lines.reverse();
+        lines.swap(3, 11);
+        let logs = super::process_log_lines(lines).unwrap();
+        assert_eq!(2, logs.len());
+        assert_eq!(3, logs[&10].len());
+        assert_eq!(3, logs[&99].len());
+        assert_eq!((10, 24), super::strategy_one(&logs));
+        assert_eq!((99, 45), super::strategy_two(&logs));
+
+        let lines = vec![String::from("not a log line")];
+        assert!(super::process_log_lines(lines).is_err());
+    }
+
+    #[test]
+    fn test_nap_end_before_start() {
+        let input = "[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:05] wakes up";
+        let error = super::process_logs(input).unwrap_err();
+        assert_eq!(
+            AdventError::Invalid {
+                line: String::from("[1518-11-01 00:05] wakes up"),
+                reason: "woke up before falling asleep"
+            },
+            error
+        );
+        assert_eq!(
+            AdventError::Invalid {
+                line: String::from("[1518-11-01 00:05] wakes up"),
+                reason: "woke up before falling asleep"
+            },
+            super::process_logs_reader(Cursor::new(input)).unwrap_err()
+        );
+
+        // swapped, the wake up is sorted first and has no nap to end
+        let input = "[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:25] falls asleep
+[1518-11-01 00:05] wakes up";
+        assert!(super::process_logs(input).is_err());
+    }
+
+    #[test]
+    fn test_guard_without_naps() {
+        let input = format!("{}\n[1518-11-06 00:00] Guard #7 begins shift", TEST_INPUT);
+        let logs = super::process_logs(&input).unwrap();
+        assert_eq!(3, logs.len());
+        assert!(logs[&7].is_empty());
+        assert_eq!(0, super::minutes_asleep_by_guard(&logs)[&7]);
+        assert_eq!(240, super::most_asleep_guard(&logs));
+        assert_eq!((99, 45), super::strategy_two(&logs));
+    }
+
+    #[test]
+    fn test_log_builder() {
+        let mut builder = super::LogBuilder::new();
+        for line in TEST_INPUT.lines() {
+            builder.push(line).unwrap();
+        }
+        let logs = builder.finish();
+        assert_eq!((10, 24), super::strategy_one(&logs));
+        assert_eq!((99, 45), super::strategy_two(&logs));
+
+        let mut builder = super::LogBuilder::new();
+        builder
+            .push("[1518-11-01 00:05] Guard #10 begins shift")
+            .unwrap();
+        assert_eq!(
+            AdventError::Invalid {
+                line: String::from("[1518-11-01 00:00] falls asleep"),
+                reason: "record out of order"
+            },
+            builder.push("[1518-11-01 00:00] falls asleep").unwrap_err()
+        );
+        assert!(builder.push("not a log line").is_err());
+    }
+
+    #[test]
+    fn test_process_logs_reader() {
+        let logs = super::process_logs_reader(Cursor::new(TEST_INPUT.as_bytes())).unwrap();
+        assert_eq!((10, 24), super::strategy_one(&logs));
+        assert_eq!((99, 45), super::strategy_two(&logs));
+
+        let err = super::process_logs_reader(Cursor::new("not a log line")).unwrap_err();
+        assert_eq!(AdventError::Parse(String::from("not a log line")), err);
+
+        // not valid utf-8
+        let err = super::process_logs_reader(Cursor::new(vec![0xff, 0xfe])).unwrap_err();
+        assert!(matches!(err, AdventError::Io(_)));
+
+        // blank lines are skipped the same way by every reader
+        let input = format!("\n{}\n\n", TEST_INPUT.replace('\n', "\n  \n"));
+        let logs = super::process_logs(&input).unwrap();
+        assert_eq!((10, 24), super::strategy_one(&logs));
+        let logs = super::process_logs_reader(Cursor::new(input.as_bytes())).unwrap();
+        assert_eq!((10, 24), super::strategy_one(&logs));
+        let mut builder = super::LogBuilder::new();
+        for line in input.lines() {
+            builder.push(line).unwrap();
+        }
+        assert_eq!((10, 24), super::strategy_one(&builder.finish()));
+    }
+
+    #[test]
+    fn test_process_logs_without_brackets() {
+        let mut lines: Vec<String> = TEST_INPUT
+            .lines()
+            .map(|line| line.replace("[", "").replace("]", ""))
+            .collect();
+        lines.rotate_left(7);
+        lines.swap(0, 9);
+        let logs = super::process_logs(&lines.join("\n")).unwrap();
+        assert_eq!(3, logs[&10].len());
+        assert_eq!(3, logs[&99].len());
+        assert_eq!(
+            NaiveDateTime::parse_from_str("1518-11-01 00:05", "%Y-%m-%d %H:%M").unwrap(),
+            logs[&10][0].start
+        );
+        assert_eq!((10, 24), super::strategy_one(&logs));
+        assert_eq!((99, 45), super::strategy_two(&logs));
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let logs = super::process_logs(TEST_INPUT).unwrap();
+        let csv = super::to_csv(&logs);
+        let rows: Vec<&str> = csv.lines().collect();
+        assert_eq!(7, rows.len());
+        assert_eq!("guard_id,start,end,duration_minutes", rows[0]);
+        assert_eq!("10,1518-11-01 00:05,1518-11-01 00:25,20", rows[1]);
+        assert_eq!("99,1518-11-05 00:45,1518-11-05 00:55,10", rows[6]);
+    }
+
+    #[test]
+    fn test_process_logs_malformed() {
+        let input = "[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:25] this line is garbage";
+        let error = super::process_logs(input).unwrap_err();
+        assert_eq!(
+            AdventError::Parse(String::from("[1518-11-01 00:25] this line is garbage")),
+            error
+        );
+        assert!(error.to_string().contains("this line is garbage"));
+
+        assert!(super::process_logs("[1518-11-01 00:00] Guard # begins shift").is_err());
+        assert!(super::process_logs("[1518-13-01 00:05] falls asleep").is_err());
+    }
+
+    #[test]
+    fn test_process_logs_overlapping_naps() {
+        let input = "[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:10] falls asleep
+[1518-11-01 00:25] wakes up";
+        let error = super::process_logs(input).unwrap_err();
+        assert_eq!(
+            AdventError::Invalid {
+                line: String::from("[1518-11-01 00:10] falls asleep"),
+                reason: "fell asleep while asleep"
+            },
+            error
+        );
+
+        let input = "[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:10] wakes up
+[1518-11-01 00:25] wakes up";
+        let error = super::process_logs(input).unwrap_err();
+        assert_eq!(
+            AdventError::Invalid {
+                line: String::from("[1518-11-01 00:25] wakes up"),
+                reason: "woke up while awake"
+            },
+            error
+        );
+    }
+
     #[test]
     fn test_worst_guard() {
-        let logs = super::process_logs(TEST_INPUT);
+        let logs = super::process_logs(TEST_INPUT).unwrap();
         let worst_guard = super::most_asleep_guard(&logs);
         assert_eq!(240, worst_guard);
-        let logs = super::process_logs(REAL_INPUT);
+        let logs = super::process_logs(&REAL_INPUT).unwrap();
         let worst_guard = super::most_asleep_guard(&logs);
         assert_eq!(102688, worst_guard);
     }
 
     #[test]
     fn test_worst_minute() {
-        let logs = super::process_logs(TEST_INPUT);
+        let logs = super::process_logs(TEST_INPUT).unwrap();
         let worst_minute = super::most_asleep_minute(&logs);
         assert_eq!(4455, worst_minute);
-        let logs = super::process_logs(REAL_INPUT);
+        let logs = super::process_logs(&REAL_INPUT).unwrap();
         let worst_minute = super::most_asleep_minute(&logs);
         assert_eq!(56901, worst_minute);
     }
 
+    #[test]
+    fn test_minutes_asleep_by_guard() {
+        let logs = super::process_logs(TEST_INPUT).unwrap();
+        let minutes = super::minutes_asleep_by_guard(&logs);
+        assert_eq!(2, minutes.len());
+        assert_eq!(50, minutes[&10]);
+        assert_eq!(30, minutes[&99]);
+    }
+
+    #[test]
+    fn test_strategies() {
+        let logs = super::process_logs(TEST_INPUT).unwrap();
+        assert_eq!((10, 24), super::strategy_one(&logs));
+        assert_eq!((99, 45), super::strategy_two(&logs));
+        let logs = super::process_logs(&REAL_INPUT).unwrap();
+        let (guard, minute) = super::strategy_one(&logs);
+        assert_eq!(102688, guard * minute as i32);
+        let (guard, minute) = super::strategy_two(&logs);
+        assert_eq!(56901, guard * minute as i32);
+    }
+
+    #[test]
+    fn test_guard_minute_counts() {
+        let logs = super::process_logs(TEST_INPUT).unwrap();
+        let counts = super::guard_minute_counts(&logs);
+        assert_eq!(2, counts[&(10, 24)]);
+        assert_eq!(3, counts[&(99, 45)]);
+        assert!(!counts.contains_key(&(10, 55)));
+
+        let logs = super::process_logs(&REAL_INPUT).unwrap();
+        let counts = super::guard_minute_counts(&logs);
+        let chosen = super::strategy_two(&logs);
+        assert_eq!(counts.values().max(), counts.get(&chosen));
+    }
+
+    #[test]
+    fn test_minute_histogram() {
+        let logs = super::process_logs(TEST_INPUT).unwrap();
+        let histogram = super::minute_histogram(&logs[&10]);
+        assert_eq!(2, histogram[24]);
+        assert_eq!(1, histogram[5]);
+        assert_eq!(0, histogram[55]);
+        assert_eq!(50, histogram.iter().sum::<u32>());
+    }
+
+    #[test]
+    fn test_minute_most_often_asleep() {
+        let time = |s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap();
+        let naps = vec![
+            super::Nap {
+                start: time("1518-11-01 00:58"),
+                end: time("1518-11-01 01:03"),
+            },
+            super::Nap {
+                start: time("1518-11-02 00:01"),
+                end: time("1518-11-02 00:02"),
+            },
+            super::Nap {
+                start: time("1518-11-03 01:01"),
+                end: time("1518-11-03 01:02"),
+            },
+        ];
+        assert_eq!((61, 2), super::minute_most_often_asleep(&naps));
+        assert_eq!(3, super::minute_histogram(&naps)[1]);
+    }
+
+    #[test]
+    fn test_afternoon_naps() {
+        let time = |s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap();
+        let mut logs = HashMap::new();
+        logs.insert(
+            7,
+            vec![
+                super::Nap {
+                    start: time("1518-11-01 14:00"),
+                    end: time("1518-11-01 15:30"),
+                },
+                super::Nap {
+                    start: time("1518-11-02 15:10"),
+                    end: time("1518-11-02 15:20"),
+                },
+            ],
+        );
+        let histogram = super::day_histogram(&logs[&7]);
+        assert_eq!(100, histogram.iter().sum::<u32>());
+        assert_eq!(1, histogram[14 * 60]);
+        assert_eq!(0, histogram[15 * 60 + 30]);
+        assert_eq!(
+            (15 * 60 + 10, 2),
+            super::minute_most_often_asleep(&logs[&7])
+        );
+        assert_eq!((7, 15 * 60 + 10), super::strategy_one(&logs));
+        assert_eq!((7, 15 * 60 + 10), super::strategy_two(&logs));
+    }
+
     const TEST_INPUT: &str = "[1518-11-01 00:00] Guard #10 begins shift
 [1518-11-01 00:05] falls asleep
 [1518-11-01 00:25] wakes up
@@ -193,1093 +701,7 @@ mod tests {
 [1518-11-05 00:03] Guard #99 begins shift
 [1518-11-05 00:45] falls asleep
 [1518-11-05 00:55] wakes up";
-    const REAL_INPUT: &str = "[1518-09-17 23:48] Guard #1307 begins shift
-[1518-06-03 00:00] Guard #3217 begins shift
-[1518-07-28 00:49] falls asleep
-[1518-03-30 00:57] falls asleep
-[1518-07-01 23:58] Guard #409 begins shift
-[1518-04-13 00:25] wakes up
-[1518-04-09 00:22] wakes up
-[1518-11-02 00:41] falls asleep
-[1518-11-21 00:23] falls asleep
-[1518-05-03 00:59] wakes up
-[1518-11-21 00:02] Guard #1459 begins shift
-[1518-08-18 00:27] wakes up
-[1518-08-09 00:48] falls asleep
-[1518-09-23 00:23] falls asleep
-[1518-03-29 23:57] Guard #113 begins shift
-[1518-04-16 00:43] wakes up
-[1518-05-13 00:33] falls asleep
-[1518-05-19 00:58] wakes up
-[1518-07-09 00:44] wakes up
-[1518-04-26 00:03] falls asleep
-[1518-08-24 00:55] falls asleep
-[1518-06-30 00:51] falls asleep
-[1518-10-14 00:06] falls asleep
-[1518-04-28 00:23] falls asleep
-[1518-10-05 00:57] wakes up
-[1518-09-02 00:56] wakes up
-[1518-03-11 00:38] falls asleep
-[1518-07-30 00:54] wakes up
-[1518-09-15 23:58] Guard #1811 begins shift
-[1518-08-08 23:58] Guard #1033 begins shift
-[1518-08-07 00:08] falls asleep
-[1518-06-13 00:46] wakes up
-[1518-10-08 00:54] falls asleep
-[1518-06-08 23:46] Guard #557 begins shift
-[1518-06-01 00:22] wakes up
-[1518-08-05 00:59] wakes up
-[1518-03-08 00:03] Guard #3271 begins shift
-[1518-07-18 23:59] Guard #449 begins shift
-[1518-09-24 00:00] Guard #389 begins shift
-[1518-04-22 00:45] wakes up
-[1518-07-31 00:41] falls asleep
-[1518-03-24 00:02] falls asleep
-[1518-05-21 00:29] falls asleep
-[1518-05-27 23:59] Guard #449 begins shift
-[1518-10-30 00:52] wakes up
-[1518-08-09 00:51] wakes up
-[1518-03-16 00:54] wakes up
-[1518-04-11 00:28] falls asleep
-[1518-04-25 00:58] wakes up
-[1518-10-06 00:07] falls asleep
-[1518-08-15 23:49] Guard #557 begins shift
-[1518-10-17 23:51] Guard #739 begins shift
-[1518-06-27 00:00] Guard #3217 begins shift
-[1518-06-30 00:01] Guard #1237 begins shift
-[1518-04-29 00:01] Guard #3271 begins shift
-[1518-11-12 00:19] falls asleep
-[1518-07-27 00:50] wakes up
-[1518-03-06 00:42] falls asleep
-[1518-11-17 00:57] wakes up
-[1518-07-21 00:48] wakes up
-[1518-03-04 00:28] falls asleep
-[1518-08-24 00:37] falls asleep
-[1518-09-19 00:21] wakes up
-[1518-11-05 00:24] wakes up
-[1518-06-24 00:02] falls asleep
-[1518-10-24 00:55] falls asleep
-[1518-06-14 00:56] wakes up
-[1518-06-29 00:47] wakes up
-[1518-04-15 00:14] wakes up
-[1518-08-21 00:08] falls asleep
-[1518-09-16 00:48] falls asleep
-[1518-10-11 00:42] wakes up
-[1518-03-04 00:09] wakes up
-[1518-03-13 00:18] falls asleep
-[1518-10-28 00:56] wakes up
-[1518-03-27 00:20] wakes up
-[1518-05-24 00:03] Guard #739 begins shift
-[1518-08-31 00:54] wakes up
-[1518-06-22 00:50] falls asleep
-[1518-06-15 00:45] wakes up
-[1518-07-22 23:47] Guard #2647 begins shift
-[1518-09-02 00:00] falls asleep
-[1518-05-04 00:14] falls asleep
-[1518-03-06 00:51] wakes up
-[1518-10-02 00:35] wakes up
-[1518-10-05 00:20] falls asleep
-[1518-05-06 00:53] falls asleep
-[1518-10-12 00:47] wakes up
-[1518-04-08 00:30] wakes up
-[1518-11-19 00:40] falls asleep
-[1518-11-13 00:32] falls asleep
-[1518-08-21 23:46] Guard #3271 begins shift
-[1518-05-31 00:56] falls asleep
-[1518-05-18 00:57] wakes up
-[1518-05-17 00:56] wakes up
-[1518-05-24 23:51] Guard #601 begins shift
-[1518-10-30 00:32] wakes up
-[1518-10-05 00:13] wakes up
-[1518-11-02 00:03] Guard #2647 begins shift
-[1518-05-02 00:36] wakes up
-[1518-07-29 00:40] wakes up
-[1518-07-16 00:53] wakes up
-[1518-09-04 00:18] wakes up
-[1518-05-11 00:33] falls asleep
-[1518-03-09 00:00] Guard #1811 begins shift
-[1518-10-24 23:57] Guard #827 begins shift
-[1518-11-18 00:46] wakes up
-[1518-08-14 00:27] falls asleep
-[1518-05-25 00:17] wakes up
-[1518-03-03 00:02] Guard #947 begins shift
-[1518-05-29 00:00] falls asleep
-[1518-06-05 00:04] wakes up
-[1518-07-26 00:42] wakes up
-[1518-10-01 00:39] falls asleep
-[1518-06-22 00:54] wakes up
-[1518-09-06 00:24] wakes up
-[1518-08-04 00:24] falls asleep
-[1518-04-25 00:35] wakes up
-[1518-11-11 00:11] falls asleep
-[1518-09-27 00:58] wakes up
-[1518-09-16 00:15] falls asleep
-[1518-07-25 00:55] wakes up
-[1518-08-28 00:27] falls asleep
-[1518-08-12 00:39] falls asleep
-[1518-11-05 00:32] falls asleep
-[1518-05-29 23:52] Guard #3209 begins shift
-[1518-04-27 00:35] wakes up
-[1518-04-20 00:06] falls asleep
-[1518-03-21 00:47] falls asleep
-[1518-10-18 00:55] wakes up
-[1518-09-29 00:31] falls asleep
-[1518-09-17 00:40] wakes up
-[1518-04-22 00:19] falls asleep
-[1518-06-14 00:32] falls asleep
-[1518-10-07 23:59] Guard #1307 begins shift
-[1518-04-25 00:55] falls asleep
-[1518-08-27 00:24] falls asleep
-[1518-08-01 23:56] Guard #389 begins shift
-[1518-09-04 23:56] Guard #409 begins shift
-[1518-09-29 23:56] Guard #449 begins shift
-[1518-10-05 00:23] wakes up
-[1518-04-04 00:58] wakes up
-[1518-08-05 00:54] falls asleep
-[1518-07-20 00:46] wakes up
-[1518-04-08 00:46] wakes up
-[1518-06-18 00:52] wakes up
-[1518-05-03 23:59] Guard #1033 begins shift
-[1518-11-01 00:37] wakes up
-[1518-03-11 00:30] wakes up
-[1518-06-06 00:42] wakes up
-[1518-06-20 00:06] falls asleep
-[1518-04-05 00:55] wakes up
-[1518-09-13 23:57] Guard #3217 begins shift
-[1518-04-21 00:00] Guard #1459 begins shift
-[1518-03-18 00:00] Guard #1307 begins shift
-[1518-09-13 00:03] Guard #557 begins shift
-[1518-05-28 23:51] Guard #3217 begins shift
-[1518-10-23 23:49] Guard #113 begins shift
-[1518-03-11 23:59] Guard #113 begins shift
-[1518-09-06 00:07] falls asleep
-[1518-03-03 00:42] falls asleep
-[1518-07-21 00:57] falls asleep
-[1518-06-12 00:08] falls asleep
-[1518-10-04 23:59] Guard #1237 begins shift
-[1518-08-19 00:36] falls asleep
-[1518-10-13 00:09] falls asleep
-[1518-08-18 00:57] wakes up
-[1518-08-28 23:52] Guard #1307 begins shift
-[1518-09-09 00:58] wakes up
-[1518-10-11 00:14] falls asleep
-[1518-07-25 23:50] Guard #1559 begins shift
-[1518-07-14 23:59] Guard #1237 begins shift
-[1518-09-26 00:58] wakes up
-[1518-05-22 23:59] Guard #3067 begins shift
-[1518-05-22 00:57] falls asleep
-[1518-05-02 00:58] wakes up
-[1518-05-14 00:52] wakes up
-[1518-04-18 00:51] wakes up
-[1518-06-17 00:35] wakes up
-[1518-05-18 23:59] Guard #389 begins shift
-[1518-08-16 00:12] wakes up
-[1518-11-08 00:49] wakes up
-[1518-08-18 00:04] Guard #1307 begins shift
-[1518-07-13 00:48] wakes up
-[1518-08-18 23:58] Guard #1459 begins shift
-[1518-03-09 00:52] wakes up
-[1518-03-16 00:00] Guard #1237 begins shift
-[1518-06-13 00:24] falls asleep
-[1518-08-16 00:04] falls asleep
-[1518-09-19 00:55] wakes up
-[1518-10-04 00:47] wakes up
-[1518-05-04 23:50] Guard #389 begins shift
-[1518-07-03 00:57] wakes up
-[1518-04-24 00:14] falls asleep
-[1518-09-03 00:22] wakes up
-[1518-11-09 00:54] falls asleep
-[1518-09-02 00:36] wakes up
-[1518-06-30 00:56] wakes up
-[1518-08-26 00:51] wakes up
-[1518-04-27 23:56] Guard #1307 begins shift
-[1518-11-02 00:10] falls asleep
-[1518-09-20 23:57] Guard #1811 begins shift
-[1518-03-14 00:48] wakes up
-[1518-05-31 00:30] wakes up
-[1518-08-07 00:03] Guard #3209 begins shift
-[1518-08-07 00:50] falls asleep
-[1518-09-25 00:57] wakes up
-[1518-10-18 00:08] wakes up
-[1518-10-03 00:56] wakes up
-[1518-05-27 00:46] wakes up
-[1518-06-07 00:18] falls asleep
-[1518-06-06 00:55] wakes up
-[1518-03-18 00:56] wakes up
-[1518-04-04 23:59] Guard #449 begins shift
-[1518-09-28 00:59] wakes up
-[1518-08-10 00:12] wakes up
-[1518-05-30 00:21] wakes up
-[1518-11-09 00:57] wakes up
-[1518-11-15 00:13] falls asleep
-[1518-06-10 23:57] Guard #1811 begins shift
-[1518-11-04 00:01] falls asleep
-[1518-05-21 00:47] falls asleep
-[1518-10-07 00:05] falls asleep
-[1518-03-12 00:36] wakes up
-[1518-08-09 00:37] wakes up
-[1518-09-23 00:58] wakes up
-[1518-04-14 00:22] falls asleep
-[1518-07-07 00:35] falls asleep
-[1518-03-15 00:59] wakes up
-[1518-03-14 00:44] falls asleep
-[1518-11-06 00:04] Guard #3067 begins shift
-[1518-07-14 00:01] Guard #3217 begins shift
-[1518-10-23 00:09] falls asleep
-[1518-07-01 00:38] wakes up
-[1518-11-05 00:51] wakes up
-[1518-04-08 00:52] falls asleep
-[1518-09-22 00:40] falls asleep
-[1518-09-08 00:20] falls asleep
-[1518-05-09 00:49] falls asleep
-[1518-10-17 00:02] Guard #3067 begins shift
-[1518-10-01 23:56] Guard #3217 begins shift
-[1518-05-10 00:58] wakes up
-[1518-11-11 23:57] Guard #3271 begins shift
-[1518-09-27 00:49] falls asleep
-[1518-09-30 00:48] wakes up
-[1518-06-06 00:01] Guard #601 begins shift
-[1518-03-18 23:59] Guard #3217 begins shift
-[1518-03-20 23:57] Guard #2647 begins shift
-[1518-07-29 00:52] falls asleep
-[1518-04-01 23:51] Guard #1459 begins shift
-[1518-03-23 00:14] falls asleep
-[1518-07-28 00:38] wakes up
-[1518-09-04 00:49] wakes up
-[1518-06-10 00:57] wakes up
-[1518-10-26 00:57] wakes up
-[1518-04-01 00:52] wakes up
-[1518-07-10 00:47] wakes up
-[1518-03-24 23:56] Guard #3217 begins shift
-[1518-07-07 00:58] wakes up
-[1518-08-10 00:11] falls asleep
-[1518-08-22 00:56] falls asleep
-[1518-11-03 00:21] wakes up
-[1518-03-28 23:59] Guard #947 begins shift
-[1518-07-29 00:35] falls asleep
-[1518-09-09 00:05] falls asleep
-[1518-04-11 00:58] wakes up
-[1518-10-12 00:55] wakes up
-[1518-11-14 00:44] wakes up
-[1518-03-17 00:47] wakes up
-[1518-03-29 00:13] falls asleep
-[1518-06-04 00:40] falls asleep
-[1518-11-16 00:14] falls asleep
-[1518-05-11 00:04] Guard #1459 begins shift
-[1518-06-03 00:11] falls asleep
-[1518-10-22 00:58] wakes up
-[1518-04-15 00:57] falls asleep
-[1518-07-12 00:59] wakes up
-[1518-05-21 00:31] wakes up
-[1518-06-27 00:09] falls asleep
-[1518-08-07 00:41] wakes up
-[1518-10-22 00:11] falls asleep
-[1518-09-02 00:43] falls asleep
-[1518-05-31 00:28] falls asleep
-[1518-05-25 00:07] falls asleep
-[1518-07-25 00:13] wakes up
-[1518-06-13 00:35] wakes up
-[1518-08-17 00:40] falls asleep
-[1518-08-02 00:58] wakes up
-[1518-03-26 00:23] falls asleep
-[1518-11-04 00:59] wakes up
-[1518-03-28 00:38] falls asleep
-[1518-10-29 23:57] Guard #113 begins shift
-[1518-09-11 23:59] Guard #2647 begins shift
-[1518-03-09 00:48] falls asleep
-[1518-08-18 00:53] wakes up
-[1518-09-02 00:46] wakes up
-[1518-06-18 00:02] Guard #739 begins shift
-[1518-10-22 00:00] Guard #1811 begins shift
-[1518-09-23 00:27] wakes up
-[1518-08-31 00:38] falls asleep
-[1518-09-25 00:39] falls asleep
-[1518-08-08 00:03] Guard #2647 begins shift
-[1518-09-14 00:23] falls asleep
-[1518-08-21 00:04] Guard #877 begins shift
-[1518-10-09 00:31] wakes up
-[1518-06-20 00:51] wakes up
-[1518-06-06 00:17] falls asleep
-[1518-08-10 00:17] falls asleep
-[1518-05-08 00:57] wakes up
-[1518-11-10 00:06] falls asleep
-[1518-05-30 23:58] Guard #1559 begins shift
-[1518-10-12 00:44] falls asleep
-[1518-09-08 00:53] wakes up
-[1518-04-08 00:28] falls asleep
-[1518-08-17 00:52] wakes up
-[1518-05-02 00:57] falls asleep
-[1518-05-06 00:56] wakes up
-[1518-03-23 00:00] Guard #1811 begins shift
-[1518-04-27 00:10] falls asleep
-[1518-06-09 00:04] falls asleep
-[1518-08-18 00:10] falls asleep
-[1518-07-25 00:46] falls asleep
-[1518-10-10 00:43] wakes up
-[1518-04-19 00:48] wakes up
-[1518-10-20 00:49] wakes up
-[1518-04-17 00:01] Guard #1237 begins shift
-[1518-05-21 00:06] falls asleep
-[1518-11-15 00:37] wakes up
-[1518-05-04 00:24] wakes up
-[1518-10-04 00:01] Guard #2647 begins shift
-[1518-03-26 00:00] Guard #389 begins shift
-[1518-08-29 00:23] wakes up
-[1518-04-02 00:03] falls asleep
-[1518-05-14 00:12] falls asleep
-[1518-04-14 00:57] wakes up
-[1518-06-01 23:56] Guard #449 begins shift
-[1518-08-11 23:56] Guard #1811 begins shift
-[1518-07-04 00:01] Guard #3271 begins shift
-[1518-07-22 00:38] falls asleep
-[1518-11-13 23:58] Guard #2647 begins shift
-[1518-05-07 00:59] wakes up
-[1518-06-22 00:26] falls asleep
-[1518-08-28 00:01] Guard #3067 begins shift
-[1518-10-31 00:03] Guard #113 begins shift
-[1518-06-24 00:48] wakes up
-[1518-03-18 00:28] falls asleep
-[1518-03-16 00:13] falls asleep
-[1518-04-27 00:57] falls asleep
-[1518-07-14 00:24] wakes up
-[1518-11-18 00:59] wakes up
-[1518-05-01 00:00] Guard #739 begins shift
-[1518-11-08 23:56] Guard #3209 begins shift
-[1518-06-20 00:36] falls asleep
-[1518-09-21 00:20] falls asleep
-[1518-05-28 00:21] wakes up
-[1518-03-05 00:23] falls asleep
-[1518-03-30 00:38] wakes up
-[1518-07-12 00:56] falls asleep
-[1518-04-03 00:25] wakes up
-[1518-03-21 00:58] wakes up
-[1518-08-11 00:17] falls asleep
-[1518-08-12 23:59] Guard #947 begins shift
-[1518-04-29 23:53] Guard #947 begins shift
-[1518-05-10 00:03] Guard #3209 begins shift
-[1518-03-04 00:38] wakes up
-[1518-05-14 00:03] Guard #947 begins shift
-[1518-03-22 00:57] wakes up
-[1518-07-15 00:37] falls asleep
-[1518-06-11 00:48] wakes up
-[1518-04-14 00:01] Guard #557 begins shift
-[1518-07-24 00:49] wakes up
-[1518-09-01 00:56] wakes up
-[1518-08-26 00:59] wakes up
-[1518-08-25 00:15] falls asleep
-[1518-07-06 00:04] falls asleep
-[1518-07-03 00:03] Guard #557 begins shift
-[1518-10-20 00:45] falls asleep
-[1518-10-18 00:53] falls asleep
-[1518-06-19 00:53] wakes up
-[1518-03-27 00:51] falls asleep
-[1518-07-11 00:43] falls asleep
-[1518-05-03 00:55] falls asleep
-[1518-09-21 00:49] falls asleep
-[1518-09-20 00:54] wakes up
-[1518-06-01 00:04] falls asleep
-[1518-05-13 00:37] wakes up
-[1518-06-27 00:46] wakes up
-[1518-04-03 00:01] Guard #601 begins shift
-[1518-03-13 00:55] falls asleep
-[1518-10-10 00:13] falls asleep
-[1518-10-31 00:21] falls asleep
-[1518-03-14 00:54] falls asleep
-[1518-04-06 00:02] Guard #1237 begins shift
-[1518-10-15 00:48] wakes up
-[1518-03-27 00:02] Guard #1559 begins shift
-[1518-10-12 00:14] falls asleep
-[1518-07-11 00:25] falls asleep
-[1518-03-04 00:43] falls asleep
-[1518-04-16 00:53] falls asleep
-[1518-06-11 00:54] falls asleep
-[1518-06-16 00:15] falls asleep
-[1518-10-18 00:46] falls asleep
-[1518-07-24 00:02] Guard #389 begins shift
-[1518-04-04 00:26] wakes up
-[1518-04-07 00:21] falls asleep
-[1518-04-12 23:56] Guard #2647 begins shift
-[1518-07-18 00:49] wakes up
-[1518-05-07 00:04] falls asleep
-[1518-10-06 00:04] Guard #947 begins shift
-[1518-09-18 00:43] wakes up
-[1518-04-29 00:33] wakes up
-[1518-07-29 00:57] falls asleep
-[1518-08-27 00:42] wakes up
-[1518-03-16 23:46] Guard #739 begins shift
-[1518-05-20 00:23] falls asleep
-[1518-07-27 00:09] falls asleep
-[1518-08-18 00:37] falls asleep
-[1518-06-26 00:01] Guard #1307 begins shift
-[1518-09-30 00:20] falls asleep
-[1518-04-11 00:44] falls asleep
-[1518-04-09 00:12] falls asleep
-[1518-03-31 00:03] Guard #1559 begins shift
-[1518-06-08 00:00] Guard #449 begins shift
-[1518-11-07 00:46] wakes up
-[1518-04-11 00:04] Guard #409 begins shift
-[1518-03-04 00:56] wakes up
-[1518-07-24 00:47] falls asleep
-[1518-08-25 00:21] wakes up
-[1518-05-24 00:06] falls asleep
-[1518-05-08 00:52] falls asleep
-[1518-08-29 23:57] Guard #3067 begins shift
-[1518-05-18 00:00] falls asleep
-[1518-08-19 23:59] Guard #1459 begins shift
-[1518-05-31 00:58] wakes up
-[1518-06-13 00:00] Guard #947 begins shift
-[1518-10-28 00:48] falls asleep
-[1518-05-20 23:58] Guard #877 begins shift
-[1518-08-20 00:28] falls asleep
-[1518-06-17 00:12] falls asleep
-[1518-08-19 00:51] falls asleep
-[1518-03-03 23:48] Guard #557 begins shift
-[1518-10-28 00:30] falls asleep
-[1518-03-30 00:11] falls asleep
-[1518-06-07 00:53] wakes up
-[1518-07-22 00:01] Guard #557 begins shift
-[1518-11-11 00:58] wakes up
-[1518-08-27 00:00] Guard #3067 begins shift
-[1518-06-06 00:19] wakes up
-[1518-10-24 00:59] wakes up
-[1518-11-03 00:26] falls asleep
-[1518-08-19 00:41] wakes up
-[1518-03-19 00:33] falls asleep
-[1518-10-02 00:53] wakes up
-[1518-07-31 23:58] Guard #1723 begins shift
-[1518-08-23 00:53] wakes up
-[1518-03-13 00:59] wakes up
-[1518-10-20 23:59] Guard #449 begins shift
-[1518-05-12 00:09] falls asleep
-[1518-05-17 23:54] Guard #1811 begins shift
-[1518-06-25 00:53] wakes up
-[1518-06-17 00:55] wakes up
-[1518-05-09 00:24] falls asleep
-[1518-08-14 00:01] Guard #739 begins shift
-[1518-03-20 00:06] falls asleep
-[1518-11-20 00:28] falls asleep
-[1518-04-15 00:58] wakes up
-[1518-05-22 00:36] wakes up
-[1518-04-12 00:04] Guard #877 begins shift
-[1518-06-04 23:52] Guard #877 begins shift
-[1518-10-09 00:07] falls asleep
-[1518-06-05 00:15] falls asleep
-[1518-06-28 00:34] wakes up
-[1518-11-13 00:37] wakes up
-[1518-09-24 00:48] wakes up
-[1518-05-15 00:02] Guard #557 begins shift
-[1518-05-27 00:21] falls asleep
-[1518-11-12 00:34] wakes up
-[1518-06-06 00:51] falls asleep
-[1518-11-21 00:43] wakes up
-[1518-09-01 00:28] falls asleep
-[1518-10-13 00:00] Guard #947 begins shift
-[1518-11-20 00:46] wakes up
-[1518-05-22 00:01] Guard #1559 begins shift
-[1518-04-05 00:07] falls asleep
-[1518-06-11 23:56] Guard #3209 begins shift
-[1518-07-11 00:51] wakes up
-[1518-08-30 00:17] falls asleep
-[1518-05-12 23:57] Guard #3271 begins shift
-[1518-03-15 00:24] falls asleep
-[1518-04-26 23:59] Guard #3067 begins shift
-[1518-03-09 00:06] falls asleep
-[1518-03-09 00:57] wakes up
-[1518-03-08 00:58] wakes up
-[1518-04-13 00:22] falls asleep
-[1518-04-14 23:46] Guard #1033 begins shift
-[1518-07-28 00:54] falls asleep
-[1518-09-20 00:00] Guard #3067 begins shift
-[1518-05-09 00:43] wakes up
-[1518-11-01 00:03] Guard #3067 begins shift
-[1518-05-12 00:55] wakes up
-[1518-03-08 00:09] falls asleep
-[1518-03-10 00:40] wakes up
-[1518-03-28 00:00] Guard #1459 begins shift
-[1518-10-14 00:34] falls asleep
-[1518-09-22 00:26] falls asleep
-[1518-10-30 00:26] falls asleep
-[1518-09-12 00:07] falls asleep
-[1518-03-26 00:58] wakes up
-[1518-07-20 00:20] falls asleep
-[1518-06-28 00:25] falls asleep
-[1518-09-12 00:24] wakes up
-[1518-05-22 00:13] falls asleep
-[1518-08-13 00:42] wakes up
-[1518-08-22 00:04] falls asleep
-[1518-07-28 23:59] Guard #1559 begins shift
-[1518-08-24 23:59] Guard #2647 begins shift
-[1518-10-24 00:05] falls asleep
-[1518-06-22 00:47] wakes up
-[1518-10-26 00:35] falls asleep
-[1518-08-10 00:58] wakes up
-[1518-03-14 00:57] wakes up
-[1518-07-09 00:32] falls asleep
-[1518-04-28 00:55] falls asleep
-[1518-10-13 00:41] wakes up
-[1518-07-02 00:17] falls asleep
-[1518-04-17 00:58] wakes up
-[1518-05-22 00:58] wakes up
-[1518-09-01 00:48] wakes up
-[1518-04-01 00:48] falls asleep
-[1518-07-01 00:00] Guard #557 begins shift
-[1518-04-18 00:40] falls asleep
-[1518-03-13 23:57] Guard #449 begins shift
-[1518-04-08 00:00] Guard #877 begins shift
-[1518-09-16 23:57] Guard #1459 begins shift
-[1518-11-06 00:57] wakes up
-[1518-07-19 23:56] Guard #1459 begins shift
-[1518-03-07 00:42] falls asleep
-[1518-06-10 00:02] Guard #1559 begins shift
-[1518-06-20 00:25] wakes up
-[1518-04-08 00:44] falls asleep
-[1518-06-22 00:01] Guard #113 begins shift
-[1518-07-08 23:46] Guard #1811 begins shift
-[1518-07-23 00:03] falls asleep
-[1518-04-23 00:31] falls asleep
-[1518-08-20 00:58] wakes up
-[1518-09-30 23:57] Guard #3217 begins shift
-[1518-10-28 00:00] Guard #449 begins shift
-[1518-06-02 00:30] falls asleep
-[1518-09-04 00:36] falls asleep
-[1518-07-24 00:56] falls asleep
-[1518-04-16 00:04] Guard #601 begins shift
-[1518-05-20 00:38] wakes up
-[1518-10-29 00:33] falls asleep
-[1518-07-17 00:42] wakes up
-[1518-04-07 00:01] Guard #449 begins shift
-[1518-08-29 00:54] wakes up
-[1518-04-08 00:54] wakes up
-[1518-03-12 00:32] falls asleep
-[1518-05-06 00:43] wakes up
-[1518-03-05 00:00] Guard #3271 begins shift
-[1518-07-30 00:04] Guard #389 begins shift
-[1518-07-26 00:00] falls asleep
-[1518-10-17 00:23] falls asleep
-[1518-09-06 23:53] Guard #389 begins shift
-[1518-03-08 00:56] falls asleep
-[1518-09-13 00:55] wakes up
-[1518-07-20 00:31] wakes up
-[1518-10-14 00:02] Guard #557 begins shift
-[1518-05-26 00:01] Guard #1811 begins shift
-[1518-05-29 00:29] wakes up
-[1518-07-08 00:57] falls asleep
-[1518-07-10 00:21] falls asleep
-[1518-08-24 00:50] wakes up
-[1518-03-09 00:09] wakes up
-[1518-08-16 00:22] falls asleep
-[1518-05-01 00:52] wakes up
-[1518-06-01 00:25] falls asleep
-[1518-07-01 00:26] wakes up
-[1518-09-08 23:52] Guard #389 begins shift
-[1518-09-17 00:35] falls asleep
-[1518-05-30 00:58] wakes up
-[1518-11-11 00:54] wakes up
-[1518-04-30 00:37] wakes up
-[1518-05-06 23:54] Guard #1033 begins shift
-[1518-11-08 00:25] falls asleep
-[1518-11-15 23:58] Guard #3217 begins shift
-[1518-11-01 00:14] falls asleep
-[1518-10-08 00:57] wakes up
-[1518-08-18 00:56] falls asleep
-[1518-11-10 23:57] Guard #2647 begins shift
-[1518-03-21 23:57] Guard #389 begins shift
-[1518-09-22 23:59] Guard #3209 begins shift
-[1518-08-24 00:58] wakes up
-[1518-10-19 23:57] Guard #389 begins shift
-[1518-09-07 23:48] Guard #3209 begins shift
-[1518-08-10 23:57] Guard #1033 begins shift
-[1518-03-13 00:03] Guard #3271 begins shift
-[1518-03-19 00:37] wakes up
-[1518-06-18 23:59] Guard #877 begins shift
-[1518-10-04 00:51] falls asleep
-[1518-09-28 00:42] falls asleep
-[1518-05-06 00:00] Guard #1459 begins shift
-[1518-05-03 00:26] wakes up
-[1518-06-26 00:55] wakes up
-[1518-09-15 00:59] wakes up
-[1518-07-22 00:35] wakes up
-[1518-09-03 00:51] wakes up
-[1518-07-22 00:58] wakes up
-[1518-04-29 00:15] falls asleep
-[1518-10-29 00:58] wakes up
-[1518-07-27 00:46] falls asleep
-[1518-07-29 00:54] wakes up
-[1518-11-23 00:54] wakes up
-[1518-04-25 23:48] Guard #877 begins shift
-[1518-08-16 23:57] Guard #1559 begins shift
-[1518-09-25 00:47] falls asleep
-[1518-03-06 00:18] wakes up
-[1518-10-18 00:00] falls asleep
-[1518-10-01 00:48] wakes up
-[1518-04-12 00:59] wakes up
-[1518-11-13 00:50] falls asleep
-[1518-09-10 23:58] Guard #3209 begins shift
-[1518-09-27 00:00] Guard #409 begins shift
-[1518-09-21 23:51] Guard #3209 begins shift
-[1518-11-03 00:11] falls asleep
-[1518-11-18 00:06] falls asleep
-[1518-03-06 00:56] falls asleep
-[1518-07-14 00:17] falls asleep
-[1518-08-05 00:40] falls asleep
-[1518-04-23 00:43] falls asleep
-[1518-09-18 00:00] falls asleep
-[1518-08-02 23:56] Guard #2647 begins shift
-[1518-09-22 00:34] wakes up
-[1518-06-19 00:08] falls asleep
-[1518-06-01 00:41] wakes up
-[1518-09-08 00:11] wakes up
-[1518-04-17 00:54] falls asleep
-[1518-05-27 00:02] Guard #3271 begins shift
-[1518-08-07 00:53] wakes up
-[1518-07-20 00:39] falls asleep
-[1518-11-07 23:58] Guard #449 begins shift
-[1518-09-24 23:57] Guard #1459 begins shift
-[1518-04-28 00:57] wakes up
-[1518-08-28 00:53] wakes up
-[1518-04-16 00:29] falls asleep
-[1518-07-12 00:42] wakes up
-[1518-06-22 23:56] Guard #3067 begins shift
-[1518-03-20 00:46] wakes up
-[1518-05-07 23:50] Guard #409 begins shift
-[1518-08-25 00:48] wakes up
-[1518-04-06 00:56] falls asleep
-[1518-09-03 00:06] falls asleep
-[1518-09-10 00:01] falls asleep
-[1518-07-17 00:11] falls asleep
-[1518-10-23 00:49] wakes up
-[1518-05-30 00:03] falls asleep
-[1518-10-12 00:51] falls asleep
-[1518-11-17 00:00] Guard #1307 begins shift
-[1518-03-23 00:28] wakes up
-[1518-08-02 00:54] falls asleep
-[1518-08-16 00:53] wakes up
-[1518-06-09 00:43] wakes up
-[1518-04-23 00:50] wakes up
-[1518-11-02 00:51] wakes up
-[1518-08-25 00:46] falls asleep
-[1518-06-27 00:36] wakes up
-[1518-04-08 23:57] Guard #1559 begins shift
-[1518-07-09 00:58] wakes up
-[1518-10-28 23:56] Guard #449 begins shift
-[1518-04-02 00:53] wakes up
-[1518-05-17 00:45] falls asleep
-[1518-03-15 00:04] Guard #601 begins shift
-[1518-03-17 00:15] wakes up
-[1518-05-05 00:59] wakes up
-[1518-05-25 00:03] wakes up
-[1518-10-18 23:57] Guard #1559 begins shift
-[1518-10-21 00:27] falls asleep
-[1518-05-12 00:40] wakes up
-[1518-04-06 00:58] wakes up
-[1518-05-23 00:20] falls asleep
-[1518-07-16 23:56] Guard #1459 begins shift
-[1518-06-12 00:51] wakes up
-[1518-04-19 00:34] falls asleep
-[1518-06-14 00:09] falls asleep
-[1518-08-08 00:19] falls asleep
-[1518-09-04 00:02] falls asleep
-[1518-05-23 00:57] wakes up
-[1518-03-27 00:17] falls asleep
-[1518-04-15 00:28] falls asleep
-[1518-09-23 00:30] falls asleep
-[1518-10-08 00:44] wakes up
-[1518-08-29 00:29] falls asleep
-[1518-08-03 00:52] falls asleep
-[1518-10-24 00:45] wakes up
-[1518-05-12 00:45] falls asleep
-[1518-09-12 00:29] falls asleep
-[1518-04-16 00:59] wakes up
-[1518-07-11 00:31] wakes up
-[1518-07-04 00:39] wakes up
-[1518-05-16 00:44] wakes up
-[1518-06-21 00:45] wakes up
-[1518-07-26 23:58] Guard #3209 begins shift
-[1518-05-08 23:58] Guard #877 begins shift
-[1518-11-07 00:20] falls asleep
-[1518-08-26 00:04] Guard #389 begins shift
-[1518-03-11 00:16] falls asleep
-[1518-11-17 00:20] falls asleep
-[1518-09-10 00:49] wakes up
-[1518-05-10 00:36] falls asleep
-[1518-05-16 00:55] wakes up
-[1518-08-04 00:01] Guard #409 begins shift
-[1518-04-26 00:45] wakes up
-[1518-09-07 00:46] wakes up
-[1518-10-21 00:47] wakes up
-[1518-05-15 00:46] wakes up
-[1518-08-21 00:50] wakes up
-[1518-08-08 00:58] wakes up
-[1518-08-26 00:57] falls asleep
-[1518-07-02 00:51] wakes up
-[1518-03-25 00:16] falls asleep
-[1518-08-11 00:59] wakes up
-[1518-04-19 00:58] wakes up
-[1518-10-15 00:56] falls asleep
-[1518-06-15 00:14] falls asleep
-[1518-04-03 00:42] wakes up
-[1518-11-14 00:18] falls asleep
-[1518-05-12 00:02] Guard #947 begins shift
-[1518-04-15 00:05] falls asleep
-[1518-04-23 00:39] wakes up
-[1518-07-22 00:19] falls asleep
-[1518-07-31 00:44] wakes up
-[1518-10-27 00:43] wakes up
-[1518-09-22 00:03] falls asleep
-[1518-03-07 00:02] Guard #3067 begins shift
-[1518-03-03 00:55] wakes up
-[1518-06-02 00:56] wakes up
-[1518-03-04 00:23] wakes up
-[1518-08-06 00:37] falls asleep
-[1518-06-23 23:50] Guard #1559 begins shift
-[1518-09-27 00:50] wakes up
-[1518-07-08 00:19] falls asleep
-[1518-05-09 00:52] wakes up
-[1518-03-06 00:02] falls asleep
-[1518-06-05 00:01] falls asleep
-[1518-05-03 00:47] wakes up
-[1518-05-29 00:41] falls asleep
-[1518-10-25 23:58] Guard #947 begins shift
-[1518-10-14 23:59] Guard #3209 begins shift
-[1518-03-04 00:02] falls asleep
-[1518-07-28 00:03] Guard #3217 begins shift
-[1518-09-12 00:09] wakes up
-[1518-03-09 00:35] wakes up
-[1518-10-27 00:00] Guard #739 begins shift
-[1518-07-29 00:59] wakes up
-[1518-11-10 00:34] wakes up
-[1518-05-25 00:02] falls asleep
-[1518-03-10 00:30] falls asleep
-[1518-07-08 00:48] wakes up
-[1518-05-19 23:57] Guard #3217 begins shift
-[1518-03-31 00:40] wakes up
-[1518-08-02 00:28] falls asleep
-[1518-11-11 00:57] falls asleep
-[1518-06-27 00:45] falls asleep
-[1518-07-05 23:53] Guard #1559 begins shift
-[1518-10-02 00:17] falls asleep
-[1518-06-13 00:45] falls asleep
-[1518-10-04 00:18] falls asleep
-[1518-06-03 00:59] wakes up
-[1518-06-11 00:57] wakes up
-[1518-04-04 00:02] Guard #947 begins shift
-[1518-04-24 00:45] falls asleep
-[1518-03-20 00:00] Guard #1307 begins shift
-[1518-11-16 00:58] wakes up
-[1518-05-01 23:58] Guard #389 begins shift
-[1518-08-06 00:00] Guard #1559 begins shift
-[1518-03-05 23:49] Guard #113 begins shift
-[1518-06-23 00:56] wakes up
-[1518-09-28 00:48] wakes up
-[1518-07-19 00:49] wakes up
-[1518-07-08 00:58] wakes up
-[1518-04-03 00:41] falls asleep
-[1518-09-14 00:57] wakes up
-[1518-07-25 00:05] falls asleep
-[1518-11-12 23:57] Guard #1811 begins shift
-[1518-06-10 00:29] falls asleep
-[1518-08-21 00:58] wakes up
-[1518-03-17 00:43] falls asleep
-[1518-03-06 00:59] wakes up
-[1518-08-06 00:44] wakes up
-[1518-07-04 23:56] Guard #2389 begins shift
-[1518-06-21 00:03] falls asleep
-[1518-04-10 00:27] falls asleep
-[1518-03-30 00:59] wakes up
-[1518-03-31 23:57] Guard #449 begins shift
-[1518-09-08 00:01] falls asleep
-[1518-04-10 00:58] wakes up
-[1518-05-30 00:45] falls asleep
-[1518-09-26 00:51] falls asleep
-[1518-09-20 00:16] falls asleep
-[1518-05-08 00:05] falls asleep
-[1518-09-13 00:23] falls asleep
-[1518-09-12 00:23] falls asleep
-[1518-03-31 00:39] falls asleep
-[1518-06-08 00:40] falls asleep
-[1518-08-23 23:59] Guard #409 begins shift
-[1518-09-03 00:35] falls asleep
-[1518-06-04 00:00] Guard #1237 begins shift
-[1518-11-05 00:01] Guard #3209 begins shift
-[1518-07-21 00:36] falls asleep
-[1518-10-11 23:59] Guard #601 begins shift
-[1518-11-19 00:59] wakes up
-[1518-11-17 23:57] Guard #1559 begins shift
-[1518-06-07 00:04] Guard #3209 begins shift
-[1518-10-15 00:21] falls asleep
-[1518-04-07 00:58] wakes up
-[1518-07-09 00:19] wakes up
-[1518-08-05 00:51] wakes up
-[1518-05-01 00:22] wakes up
-[1518-03-07 00:59] wakes up
-[1518-08-12 00:33] wakes up
-[1518-05-03 00:18] falls asleep
-[1518-03-10 00:04] Guard #3271 begins shift
-[1518-10-31 00:55] wakes up
-[1518-07-03 00:48] falls asleep
-[1518-05-31 23:52] Guard #3271 begins shift
-[1518-05-23 00:37] wakes up
-[1518-06-13 00:59] wakes up
-[1518-11-06 00:29] falls asleep
-[1518-10-27 00:09] falls asleep
-[1518-05-07 00:50] falls asleep
-[1518-06-28 23:53] Guard #1459 begins shift
-[1518-04-04 00:37] falls asleep
-[1518-10-09 23:56] Guard #3209 begins shift
-[1518-06-29 00:02] falls asleep
-[1518-09-16 00:56] wakes up
-[1518-05-08 00:31] wakes up
-[1518-10-08 00:20] falls asleep
-[1518-04-21 00:58] wakes up
-[1518-03-09 00:56] falls asleep
-[1518-06-13 23:58] Guard #1033 begins shift
-[1518-07-22 00:42] wakes up
-[1518-05-28 00:11] falls asleep
-[1518-06-17 00:00] Guard #3271 begins shift
-[1518-09-27 00:56] falls asleep
-[1518-04-18 00:00] Guard #947 begins shift
-[1518-09-19 00:04] Guard #3209 begins shift
-[1518-11-18 00:49] falls asleep
-[1518-08-25 00:42] wakes up
-[1518-04-20 00:56] wakes up
-[1518-08-22 00:47] wakes up
-[1518-10-07 00:59] wakes up
-[1518-04-18 23:59] Guard #389 begins shift
-[1518-07-16 00:23] falls asleep
-[1518-07-30 00:17] falls asleep
-[1518-04-24 00:03] Guard #877 begins shift
-[1518-05-06 00:38] falls asleep
-[1518-05-28 00:44] falls asleep
-[1518-05-02 23:56] Guard #557 begins shift
-[1518-11-17 00:47] falls asleep
-[1518-06-18 00:44] falls asleep
-[1518-11-04 00:14] wakes up
-[1518-06-16 00:30] wakes up
-[1518-11-02 00:22] wakes up
-[1518-04-22 23:57] Guard #601 begins shift
-[1518-06-20 23:50] Guard #3067 begins shift
-[1518-07-28 00:06] falls asleep
-[1518-08-25 00:28] falls asleep
-[1518-11-06 00:47] falls asleep
-[1518-05-16 00:49] falls asleep
-[1518-09-15 00:40] falls asleep
-[1518-09-25 00:44] wakes up
-[1518-06-04 00:58] wakes up
-[1518-03-08 00:50] wakes up
-[1518-10-30 00:58] wakes up
-[1518-05-01 00:25] falls asleep
-[1518-11-13 00:51] wakes up
-[1518-10-06 00:54] wakes up
-[1518-04-28 00:51] wakes up
-[1518-04-24 00:33] wakes up
-[1518-03-25 00:51] wakes up
-[1518-11-02 23:58] Guard #877 begins shift
-[1518-07-12 00:00] Guard #1459 begins shift
-[1518-09-12 00:45] wakes up
-[1518-11-11 00:47] falls asleep
-[1518-10-08 23:57] Guard #947 begins shift
-[1518-06-08 00:59] wakes up
-[1518-07-10 00:04] Guard #3217 begins shift
-[1518-09-03 23:50] Guard #1459 begins shift
-[1518-09-01 23:50] Guard #3271 begins shift
-[1518-11-19 23:56] Guard #113 begins shift
-[1518-04-22 00:00] Guard #3217 begins shift
-[1518-07-20 23:58] Guard #877 begins shift
-[1518-09-28 00:03] Guard #3217 begins shift
-[1518-03-04 00:18] falls asleep
-[1518-09-03 00:03] Guard #1307 begins shift
-[1518-09-14 00:48] falls asleep
-[1518-09-02 00:49] falls asleep
-[1518-03-24 00:46] wakes up
-[1518-07-24 00:59] wakes up
-[1518-09-01 00:02] Guard #449 begins shift
-[1518-04-25 00:24] falls asleep
-[1518-06-11 00:28] falls asleep
-[1518-07-09 00:49] falls asleep
-[1518-08-31 00:02] Guard #409 begins shift
-[1518-08-05 00:02] Guard #389 begins shift
-[1518-08-22 00:58] wakes up
-[1518-09-29 00:52] wakes up
-[1518-09-07 00:01] falls asleep
-[1518-10-19 00:15] falls asleep
-[1518-07-18 00:39] falls asleep
-[1518-07-06 23:58] Guard #113 begins shift
-[1518-11-10 00:04] Guard #1033 begins shift
-[1518-08-15 00:01] Guard #1559 begins shift
-[1518-04-12 00:13] falls asleep
-[1518-06-05 00:56] wakes up
-[1518-10-17 00:54] falls asleep
-[1518-10-10 23:58] Guard #1459 begins shift
-[1518-08-13 00:14] falls asleep
-[1518-10-16 00:02] Guard #2389 begins shift
-[1518-07-11 00:01] Guard #557 begins shift
-[1518-10-02 00:42] falls asleep
-[1518-07-15 23:57] Guard #449 begins shift
-[1518-03-07 00:45] wakes up
-[1518-11-19 00:02] Guard #389 begins shift
-[1518-11-03 00:51] wakes up
-[1518-10-22 23:59] Guard #1459 begins shift
-[1518-07-08 00:02] Guard #3271 begins shift
-[1518-09-16 00:18] wakes up
-[1518-05-05 00:00] falls asleep
-[1518-09-15 00:01] Guard #2647 begins shift
-[1518-05-19 00:46] falls asleep
-[1518-10-17 00:48] wakes up
-[1518-03-07 00:57] falls asleep
-[1518-09-11 00:52] wakes up
-[1518-10-13 00:53] falls asleep
-[1518-07-13 00:43] falls asleep
-[1518-06-26 00:30] falls asleep
-[1518-07-28 00:59] wakes up
-[1518-08-12 00:15] falls asleep
-[1518-06-25 00:21] falls asleep
-[1518-05-26 00:38] falls asleep
-[1518-07-12 23:59] Guard #1811 begins shift
-[1518-05-28 00:47] wakes up
-[1518-10-02 23:56] Guard #1307 begins shift
-[1518-09-22 00:47] wakes up
-[1518-05-07 00:35] wakes up
-[1518-08-09 00:17] falls asleep
-[1518-10-30 00:42] falls asleep
-[1518-04-04 00:18] falls asleep
-[1518-03-22 00:44] falls asleep
-[1518-06-13 00:57] falls asleep
-[1518-05-02 00:29] falls asleep
-[1518-08-15 00:55] wakes up
-[1518-04-19 00:57] falls asleep
-[1518-04-19 23:59] Guard #947 begins shift
-[1518-05-02 00:46] falls asleep
-[1518-11-15 00:00] Guard #3209 begins shift
-[1518-10-13 00:57] wakes up
-[1518-04-03 00:07] falls asleep
-[1518-08-15 00:20] falls asleep
-[1518-03-13 00:47] wakes up
-[1518-09-19 00:09] falls asleep
-[1518-11-17 00:21] wakes up
-[1518-10-19 00:35] wakes up
-[1518-05-15 00:40] falls asleep
-[1518-11-03 23:48] Guard #1307 begins shift
-[1518-04-10 00:04] Guard #1307 begins shift
-[1518-06-20 00:04] Guard #3217 begins shift
-[1518-05-16 00:04] falls asleep
-[1518-07-19 00:12] falls asleep
-[1518-10-12 00:23] wakes up
-[1518-03-11 00:56] wakes up
-[1518-05-23 00:56] falls asleep
-[1518-06-28 00:32] falls asleep
-[1518-05-11 00:45] wakes up
-[1518-04-09 00:33] falls asleep
-[1518-10-05 00:10] falls asleep
-[1518-06-30 00:37] wakes up
-[1518-07-04 00:06] falls asleep
-[1518-05-02 00:53] wakes up
-[1518-10-06 23:54] Guard #557 begins shift
-[1518-08-23 00:17] falls asleep
-[1518-09-11 00:19] falls asleep
-[1518-03-27 00:58] wakes up
-[1518-09-06 00:01] Guard #1237 begins shift
-[1518-07-06 00:45] wakes up
-[1518-08-04 00:48] wakes up
-[1518-09-19 00:25] falls asleep
-[1518-04-11 00:31] wakes up
-[1518-04-21 00:20] falls asleep
-[1518-10-30 00:57] falls asleep
-[1518-08-03 00:58] wakes up
-[1518-04-27 00:58] wakes up
-[1518-10-15 00:58] wakes up
-[1518-11-23 00:42] falls asleep
-[1518-06-15 00:01] Guard #1559 begins shift
-[1518-04-24 00:49] wakes up
-[1518-09-21 00:31] wakes up
-[1518-05-26 00:41] wakes up
-[1518-07-28 00:51] wakes up
-[1518-11-22 23:58] Guard #2647 begins shift
-[1518-10-28 00:39] wakes up
-[1518-09-14 00:37] wakes up
-[1518-05-15 23:51] Guard #3217 begins shift
-[1518-07-01 00:33] falls asleep
-[1518-03-29 00:44] wakes up
-[1518-05-22 00:51] wakes up
-[1518-10-14 00:54] wakes up
-[1518-07-15 00:45] wakes up
-[1518-06-14 00:27] wakes up
-[1518-07-13 00:55] wakes up
-[1518-09-28 00:54] falls asleep
-[1518-10-18 00:50] wakes up
-[1518-08-26 00:27] falls asleep
-[1518-11-04 00:53] falls asleep
-[1518-05-01 00:10] falls asleep
-[1518-08-02 00:47] wakes up
-[1518-04-30 00:05] falls asleep
-[1518-09-05 00:56] wakes up
-[1518-09-01 00:55] falls asleep
-[1518-06-15 23:56] Guard #1559 begins shift
-[1518-09-09 23:46] Guard #947 begins shift
-[1518-06-06 00:36] falls asleep
-[1518-07-24 23:48] Guard #1237 begins shift
-[1518-07-01 00:08] falls asleep
-[1518-07-12 00:37] falls asleep
-[1518-03-30 00:54] wakes up
-[1518-06-17 00:48] falls asleep
-[1518-11-07 00:03] Guard #1459 begins shift
-[1518-03-28 00:58] wakes up
-[1518-08-03 00:48] wakes up
-[1518-06-09 00:32] falls asleep
-[1518-11-05 00:11] falls asleep
-[1518-11-21 23:52] Guard #409 begins shift
-[1518-11-22 00:04] falls asleep
-[1518-08-22 23:59] Guard #113 begins shift
-[1518-11-06 00:41] wakes up
-[1518-10-03 00:40] falls asleep
-[1518-06-25 00:00] Guard #877 begins shift
-[1518-05-17 00:00] Guard #449 begins shift
-[1518-06-28 00:03] Guard #3271 begins shift
-[1518-09-29 00:03] Guard #2647 begins shift
-[1518-05-21 00:19] wakes up
-[1518-03-30 00:53] falls asleep
-[1518-08-14 00:49] wakes up
-[1518-06-23 00:37] falls asleep
-[1518-07-31 00:00] Guard #3217 begins shift
-[1518-06-09 00:29] wakes up
-[1518-03-11 00:00] Guard #389 begins shift
-[1518-11-11 00:27] wakes up
-[1518-08-21 00:57] falls asleep
-[1518-08-09 23:56] Guard #1811 begins shift
-[1518-04-25 00:04] Guard #2647 begins shift
-[1518-07-09 00:03] falls asleep
-[1518-09-25 23:58] Guard #1033 begins shift
-[1518-07-17 23:58] Guard #409 begins shift
-[1518-05-22 00:47] falls asleep
-[1518-08-30 00:32] wakes up
-[1518-10-14 00:23] wakes up
-[1518-10-17 00:56] wakes up
-[1518-08-12 00:59] wakes up
-[1518-09-21 00:58] wakes up
-[1518-09-22 00:22] wakes up
-[1518-05-29 00:52] wakes up
-[1518-09-05 00:51] falls asleep
-[1518-10-05 00:52] falls asleep
-[1518-03-09 00:29] falls asleep
-[1518-04-09 00:50] wakes up
-[1518-10-04 00:59] wakes up
-[1518-07-27 00:33] wakes up
-[1518-07-13 00:51] falls asleep
-[1518-06-28 00:29] wakes up
-[1518-07-23 00:44] wakes up
-[1518-05-24 00:58] wakes up
-[1518-09-24 00:07] falls asleep
-[1518-08-19 00:55] wakes up
-[1518-04-15 00:53] wakes up
-[1518-08-29 00:04] falls asleep
-[1518-07-21 00:59] wakes up
-[1518-05-03 00:45] falls asleep
-[1518-03-17 00:01] falls asleep
-[1518-03-05 00:51] wakes up
-[1518-07-22 00:46] falls asleep
-[1518-06-30 00:14] falls asleep
-[1518-08-03 00:32] falls asleep
-[1518-11-22 00:08] wakes up
-[1518-05-21 00:58] wakes up
-[1518-03-23 23:46] Guard #3209 begins shift
-";
+    lazy_static! {
+        static ref REAL_INPUT: String = read_input(4).unwrap();
+    }
 }
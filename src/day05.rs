@@ -17,7 +17,7 @@ fn polymer_reduction(s: &str) -> usize {
         }
         let l = result.len();
         let last = result[l - 2];
-        if last.to_ascii_uppercase() == c.to_ascii_uppercase() && last != c {
+        if last.eq_ignore_ascii_case(&c) && last != c {
             result.remove(l - 1);
             result.remove(l - 2);
         }
@@ -32,10 +32,13 @@ fn remove_chars(s: &str, c: char) -> String {
 
 #[cfg(test)]
 mod tests {
+    use crate::input::read_input;
+
     #[test]
     fn test_polymer_reduction() {
         assert_eq!(10, super::polymer_reduction("dabAcCaCBAcCcaDA"));
-        let input = "vVuyYJjUzzZPxXqQpZmgGMvNmMyYTtnVkWwKVvOosmTtMSkDdKFsSpEePhXEexzZtnNTHEeGoOmMRrGggfnSsNwWVvJGgFEefpPMmeUuEoOBbjJuUwWjhHUMmjYyJsGgiISEepjJGgPZQqoOzyKkvVWXxhpPFcCfreESsjJRHUCcXGyYgQXxCcqXfFvVcCLlxxuwMmzZkEeoOKHhHLzZFflhiIOoHhjJaAJjYyHJjhmMYUwSsWUNxXUuFfuKkbBUtTnJjuNeEYyTxXtYyeIixXsvVQqSbBrRIifIpwIFfiWPiFEnaPFAafkKaKkAFfptTTtACHhWwcKkBbwgLlfFGWPUuJiIZFfznNjvVpSAaQqsnRrOojJsdmMDSVvRfFWvVwhaAHrwWUyYugAqQqQaGEexIiXVWwvbcCcCOaAoqQBLlNtEsSyYntTNeTHhZnNzJjSsCcWgGjJwzeEZaqQHtThALlBbnNJHhjbGgRrBPrReUcmPpMqQCuWwNnELlfzHhrRZKkhHFqQXxmjJMIiUuGgfRrNtTWzZwnlGgSssSsSrROoGguUdDkKwWyYghHGLDdxXoxXOFftdOoRSsrfFArRaSsELleDdDtRqQTJjtCSscjJbBrTevVrRQqDZzdYyYYyrkKRywWHRrhgUuGdDspPSrbBRZzYmMoOjJTtySszZiIzZdXxEeDJjUIizZuUuUVvucdiCcILHhHGgoOcCZnNzRVvWqwWfFexXEEeQwGgrBbhaZzAfFnDBBzZbIibOaAQqooOOqlLuUVvnvVNQWwoqDdgUuGSsSZzLlsQrRGgfFBbdnNOoNyZzYEelLcCPjJxXpHUuhPuUpPWsSGgRzZpPjlLJrUTtuweqQExXcCfFwlLtXxTWFGgRrcCiBbIKkHgMPpmkKGwWhgcloOxXBbnYyNlLLeDdmOoeEMEnTtNuUtTjJIlpPLQUuqmeEuUMCWwciuUnNnYyNfBbObBoFynNYCCkKoTtOFXxZzugIiGStTsGgxXBbFfiIULtTpPSslCcfnNYtTxXycAaaALtTlUrWwhHWWwwcCRhHEZzdDeJjoONKkAaWwXxntrRTROortzdDZTuiIAagGrRCYysScXLlYyTtxUudDzZHaAmdPmSsvVMpsETteDdScPpCDfFwWQqrBbRBbBTUeEutEeTtKkpsSPCcAglLGQqFKkNnsSfbBOowWahHPRCcrpaAvVCPpcnNetTqQEnNLuUlExVAaBZzbvXvVHheWwRkRrKQqYyFUufrRbBrHejJEhdSsDGgqMUuuUmtTiInNPLyYDigGIHhdlpnNgGANzTtZIinYoOZzaAyVvYyXxpVeEMMmmDjJdVvqwgGXfFxMjJmWQdDLlkKvvpwWPRrxXMmAyYaVEuUnNgGotTYyMmvcCVOYyYcCEeEeVvsSmMdDyKkjJWQqSsLlqQwemePpEMtiITPagGQZzqUrRXxhHxjJXuUuQzZKKkrRmMKvVpPkzzZqQZkjzZRrZxVvRrcCMmcCTnNBbNYynqQEQqetXxTtWwXzJvFfsKkFfHIpPihSVktTCcWwKDPDdpPpDOrhHCcRTMmEeTttDdwWZhHZzaAoeEgGOGgzZtTzoPRrRrFgGflJjFfGgBbEtXxTewWKkLnpPiIlLdDNphtJjnNAaDdVGgvfFwWvVOyYqqQdDRrjJQoMmeETOojsxXkKdSTwvVWtPBbpsqpTtPQtThsSCsScHmSGgpPtTtTIiPpdDDdDAnNadseEMyYDiIFfiMHhpPOHJjfXxyYFJjKKkkiHhHhnYwWaYyAlLxfFXyEetTNlfEeFLSmMxXecnNAaCAaJJjYykIWwnNfFifFdDkKKlLVvpPolLkoOaAKZEAhHYbByyYaVvNXxnTdDmMjJtDdzRrkKTtZBbeOrBbRoiIyYRrIcCbBCcUuiIYyDdFfHdDjxiICluULlMmLwWiIqQvVrLTtlRnDdVvwWNoDdOsSkKqoOQiIcEebBkKZzSOosJhHdDeEmMvfFXYyxzZlolLBbOLGgjDdaBbAfFJVvGnNgtSsAaaAyGgxXYGNncrSaAsJjRcpjJkAaAaKPVvCkwWKiIjJlLCgMmBbQqQlLiItToEeOwbdBAaFfNmPpirRgGIMoOXnNeExZkKznJjWrNMmmMSsHFMmfBbBbgGfSsOoFJjaNnMmKuUkAFfXxrPCceEpdDFfKkRhLluUnNnbBXIoOiyYvVcCXxxSrRaAJXxjslhHmMLXkKOSsiaAIUuuUUuLliIbBuUUuEetTUuWYyuUqXRUuFfPpMmlrKkRkKLrtTlLVOBbovyYxjnNdDWfWweEFwubBUJMiIOueECcAWwrRSsxXiIayYUPpmMicCEsSmMbGgBAaTXxtqgGQmMeAaFmMmMThHtfDdcCxXIFGgfPgGppgGiIrRIidDIzbhHBsJLljSJVvjZvdDKkzZXzVLlXvVjJxvIZVvzRwVvxXWUNKknRGgrIipWwPeEudDPpCVvcxXHhsxFfXlLSsHhmMHVbzZBvhSGgZzYySsgwLlYycwcTtCQqOoWChHWGMuUmKkgNnkKzZlLeElLLlGwZpPzzZRuUrgxXotTOoOZVvzcCCcKkDdPpRrFxXZXxMgGmqQKkxXzVvQqdvnHhgUuGNJsSjVDZzdlLgGDbEeBPpREbBAaeGpPvVgDQqCccCmMdTtVviIKkrAaNnBrRrrRSJjsJzZNnrRjRaAQqJMmpPXlLUcCuFfJIiQqjxBHhVrgGOoRQtEeTqjJwcCaAWvFlEeLuPWNnwpfXSsxkFfKWwFEOOooAaRdgGDrJnNjgGyCcYTLlWwKkZzWwteUkVvKhAarRHMrReEqPpQmfFrwDmMdmZznXxPpNMwWbBrBWdDwzZblIiLcyYCRkKRVZFfqQzXgGcCGggwBbDdtTLlJQUuqQOonLkKLlRLlrlNRrQqaAcrYyMmRQkKqCpPfFZzxXpxXRVvLkKlfFOoKkQqrwWPpdDPJjrrRMmRfZDAaJMqQmjPpPFkKMmfCIicpDdyYyCcYVtTvBbfBDdbqQFBbzFfZyJjiIozZDdoOOWGjZzFfpPcCVvJgwSsPpxXEebzZBddTtDcCXxNnDHhEdSuwgdamMAGgzZBMdDkKmdDVvSsIliILHhVvpPFeELJcCTtGgWDdwcvVClLeEDyYdtvVQJjQSlLspPBbDdlIiUurRLlKwWDAaRzZrKkHhSsdJEejkbBUNnsSfFzZJjxNnHYyOoYyZziIgGhxXJjGwgOoGpPHhWgRfFYRxXryjnYyoOYHhnNyNrjJRIxXiJrREeUbBurnbBNtmMTHLgGlOkKSsgtghHMxXmhcCHRrLlqjJQQCcqXxIiGMkhHKSrRoqBbgGfFQuRrUMHZCcKKkkzEelLxXhmMchTttSsLloOrRGgeGgtkKhHTlLEOgGpPoEeaOoKbKkYyBFfyYmMYTtxXTtynYyGgXDdFfFfxAifFIaNJjvLnNPpLXxCcWXcCpPVjJtTvbEeBxmSsQqYyPpZRrBlLbvVjUuRDRrPpJjuUicCGgxdDqhHeEkVvKRrJjtTSsEBboOxXHUyYRrkKWcCwuIiVvhJMmjGgCcdZzDhHDdGioOUJjlVvLqQuUuciIwWiIcCCJjQOoRrbBCQqsScxbBTOoKbBlLEekrRYCIiHhcOoaAFfyQYWwyqxtTUuyZfrRyYFkMmqTtQuPpUUXGgxlYTtycCFYyOrRoqjJQHhxXfKkcCukKzZSxqQXjpvVHVvRUNpPnfFcbBCuyYrkvVKceECJjEehHdDOooOhIincyIiYCNTYytRwWKEekMmMbEeyYfFuUQquUnNBUubBmPpLIXxilnJjMmAacxqQXiIqmpPMMmlBtHhIWwgJjiIeXZMmkKpPSspPBiIbZzpPAZzazNnwWMvVVbBvMYKkpPNxXnUuuEeUEcCeJjJjAKkVvahlLuXxUHJAajUuGgpPTWwxsUuzZkKfPpbBkKFxXlkerREKrRwWLlLkKSGumKkMUgcCXHxDYydPfFpOZzgGjJcCwWBbbyEeGedDeEdDvVEdcCBbssGlLFfUutLxAaXlToOgCheEHcZzEgGetGgOnphHPNoxXeEdDOoTteEoOTbBPpQGgqfFAWwaYcmMdIiYCcyDCONnFfoXxOoiIyYyEeuJVuUILliFLlUudSxcCbgGByYXzZNngGcXxCHNnTnBbEesPtTpGgnNSXxNdRrEeUYyVvueEEAaegGZzIuUiHhCcAaqZzQOoDxHqQhCcXbHNnhbBBrCcRiIKkdDYBjJbGgzZyZzsSvHhVjfFiIpSNnUuDPuUpdsauWwhHUOokKDdARDdQqIikNxXrRnmMPHhfFnNgGpKlLrlLwWLlJjIiPnfIiFYUmMOouFfyhkKHqQBaGgcbBCaQqdDAetTbUuBVvMmdDEmWMmwkKMdTZzZzgGdDgGgGiIdDlLhVoOvFfgGEeMUNWwmMmvVMuUbGrRIifqQLPpwWlRUurcjJchoqQONnyLRrlmMQqUpExXdDeEjLOoliLuyrRGgYPppPYyfFuYyQqLlSsUWwnNzZpEeyaLlmMAYPaAvVpxXPKkjRuUlJjyYLSssSvVrYygGRrgGJRrqQqdDQeElMmsSdxXMmDvUuVIiLUIdDDwWdojJOiIiuKkQrEeRqULZzlCcEekoOrRkGbBgKJjzOkKeyYEofFSsecakKAzZCuoOlddDDIBbiQqixXpPwWkXjJYyxIiKcCfKkWAatTzZnNBblrRoOtTLtTQqtfFTVvtTsSMrRMmmGgqqkKDdQQxXNnYgGywBbfyYPpraWwAEEezZeWdDdDuUwQqvbNsSnlLBkKkKtTpPVWYyzZaHhOMmtqQTnaANyebBEOoymMXrRxYywvQqfqQFYkKsSYylHhLHhLlTXxYygGFRrfwWGgvVOoUuSifFIMmLuUuUYXxylRbBXiIWwxreEBbMmuUuUNjJjJprRPIMnNmisSehHEvVqqQJjpPIijfFJnNQdDXxKcCrRZzqpPnNDEewWdfrRmMCZCczZzHhKkcjJCCcXxEeMaAiInZzNMaAmmLnNCdrRaAtTDLlYyTTpNnPAatBsDbCcfHhFBhHdGgKIdDiXPpxNnkxXAaAzBbZakKrOoGgRHXxhttVVvmMmcOoGzZGLlgORJdDKkjEegGsbBAUuvVaVgumMbBoxXbBVvSslLxXkKqbBnNsMmcEeCqQSQeEkIiKSsbBHIkKitTfFfFPwWkKLlbBFGgfEEJjlLzCcZkVvKYtTyRcPpTtAGNngaDdPWNnnqyYQhLltTbhHXxEeARlEeUoOuqoCyYTtciIOPpQwWLtTVvfzZVvvwWnNnCcjJOcCdDwFfOIVvTtkKfQqFmMfiIBbFEesaCcWwLlxUwZEQqePpbNnQqUuVEecCKZzBlLFfbvVQqYcCypPrAXxiHiIGSdDoULlGgTZzttTMmbdDBdDusNdyYDFfEXxPpHyfQqFPgGpVvtzBbkeEFfsFfSsHhKkSwWHhLltTFndDjoOJHhlLQqVvlLIilMmNnhHEPpeMIiAsSamWABbJjVvgGxXQFfAaXxhnNIimMJjIFDLldAuUNnTtaeEuULFflEkbvgGgGhPUuIUjJuRrRriPpOoeEpHInNqeEdyYtqQTCcNhZfFzTtWpsSPBbOoLlcCapgGHhHoOomMvVcCOuUhpPmMoNncCOGgNnHhFfVvAoOKkVvzZakNoOhaAGgpiaaOoVvxtTXAvVIcCiZRrzOsSrreGgnjJtTNuvVUxNfFLlpPxXXxuKkTqMmCcQtEeUSslLRMmUurQqjmMVvgGaYycCCMmkmdDMCzNhHnuUzZZcFbBDcCvVdfxXxTtXxpYykKpPJjxNnXqYyQFfbbIhHOoivVBBKkyYcCgFbBYZzkKmMPVvptTSsiFPdDNnhHjYEetwWwWiITyzZJpfiIMmNZznKkYiIyqQElLjAaXPprRqQNvVnxcCkcCKvZzOpPoXyYxhHOoFSsjAkKaCJjjJSQqMmFfZEnNeuWyPpabBAbsSBbcnPlGgLlLzZSBbtTsPeEGgzCcgGZlgGnNWuUwZztTEPnNOouUpqQwOmPpPpMOoWPbWwVDdWHvVhJtZzTjwMmsSvTtwrRWgGxNnXMmhHkKyYeEAaNGgJjzbBZHzZxmMsoOQqSlLXeEtCcMNnQqPpmwWVdDrqQmMRvVvLsZUusFfnaEeArbmMjJBRgGUeEuNdDrjJESQqsiILWwekKQEHheAazjJxOoEenkKNtTNqQrzuUZkVvmMmlMcCdqQDmxXSIisOtuUOgGoTRrCcuUvVdDcCnNnzZMmXxxXPnNbBpHhNHhrFjFfJJjfGkjgGJhjHhqdDFyryuzZyYAUsSuawWbfFVOovdDtTXBbCcfFfOolLFLlGgxpPuxXVtTDfFdYZzykXxDdKQQqgpsSPGqvVzZvTtvndyEeYqdSSsCcpPLlsrROoJJIinNjOojrRIiKxXmMMmkoZjiuUIJzEjJelwDdWLcCtTsjIiJJjJbBCDdcvzlLZAxXtTaUuFsJjuUIiIiyYyeEYiIfFSfIiwGgWKkSsGpPzHhSsokMmNnxoOMmJjPpMjJqpPiIQJjjHhgqQGwtTjmMzOmgGeEMRKkUKkKkYyzeEQUuBwWDcCdVvbeEqjJZCczZNnxkKFfHVkJibwWPpmEdDxHhjGsSgeSsvYyeEVsSErRLGOottTeEBbbBTnNmoOVcExbBNnVvXEegGwWWwwWVROorORroPpvLTEelAarkKqUuQiIjJjWwJosSOAjBryRfEvVeFrYRzQqtTYyNMmBbKmxezfeYygGRGgvVInNiYBHhgGoswTCctlLWSlrRjJVNOuvFfaqQuUEeDVvdfFLlcAYyIiJoOYytUuhUuTtZDRrYnNyIiDdQqBjJcRrgGCKJvCcUuyYRrARzZiIypUuiIPYwzZOWwHhoBbWrdbBbcKkCGKkEhHOoiIhEdDJjeyYnNcIieKkEhwWKwmTMmlLzoOZgxyyYYXREpPwWGgOyYvVoOojJLlOotThHRrAViIdDRrGRUWMqQWwfFZlLVnHhNvzbCcBkKJjFMmfjgGWwUIPpyYiEexeEbBCiwWfFIcLbBujJMyWVvwYmKkUfFYylcsVaAvSRxXrPjJpCoOIkmWwqQQqYyLliyYIAaxXeEMeHwSbBHhsRmMVmMfKkFRcCglYyRrdYyDJsSIiVvCcIWwiZzjDVXxvdSsEVvQqWweDdTtZzyYCcQqQTtxOyYtWwXnNvyYAYyaTDbQJjqIiLlBxXotcCJsSrRjHhuUaANvRrVnAuoOUaMmqFfEeQcCTaAOaYUBOhHofFXpPxZzbFqQceMoOuQLlmMqUKkroORmVvuUvGgOtirRFpPfITLlfFGtTgoUpMcjTtffFAQqrkKRkCLlcgGKxVKklRrLfFlTtEeFfbVlLvCctTlLqDdhNnHQAnNnNaaAaRrNZzNnBbndDpCCciOoIuUUAALRyiIsWJjTuVYhIipVvvVPMFfVvlPBbyrRpPeEYyYpzZpPXxuhHUwWLPpFSDVvdqLcClQiaAHJjIdDiiINnsSkSsqsSkTVvTIiPHhmqQMpSgGszXxZvJIiVtTvIvvVvBbVHhVULlgYjJfZzdzZLlyYhHHhGgzZWrRNklLKmMZznWwhOoJjQgGfsEeunNXxyYlhHcCjJLURSRaAryYVuUzUOAawRrRKkQqfFrWvVUuMmaDShHhJjoTtsSbsHhSdnNDEexXSWdaBbBbAFfELzZlTtxeQcCSsqCXJjxkKtEetpavqQVPYyKMmCcvVkUrPpREewnNWvVAhHNoOOopPZzngGdDCiVveEKkIinEejJNdDuUIcZzYyiIovlLSsVtTIieOzZMmoFZzfKzZkjOvGgetTOorReExUJjuTtsSAsSFfaXCDdchHrRKkXxhjJhIVvErReBZzFfiDdIbKGgOSsGgnNolLuZzoOQPLzbAaUuBqQtTiIREWwSsefewWaWAfFinGgNlnNcBbCrQhHqRCcjJLyYiIIrHeYOoygtTGOoRSsyyYLMmUumMXxFfsSsSFfpPIOoGKQqXxrRkgiTtLPpLlllHhYNmMTtPdgGktTKDhHkKLlpnszZSTtZzVDdhHmMjZNgchlLGKkIiyNmMiISsoOcOFhIiDduoNnuUZcCHAJjbhuHRrexCcXEhZzyXxYdDeEyEelfPpUPpkjczZCJTRrbrdDwWRfFBtTfHhMmFkfLSsXxHTthsJwXxWjCcZHhbBwYyjJciIUJxoOXjuCkDdwWxrRXiIBSYyuutTpnDjJdzGxwWwdDEeAlZCcXxzLuwWgGnxXNOowjMmzZwHhWJUZhHzhHudvpPVSsDrRSMmsNpPdatBPpqQAaRrbjeEUuJhFfHkKFfHQxXcKkmSsMLkLlBbUuKJUlfNnUuFuUVvNnFAQqsSZzHhqdDqtsSTLHhfUuqqxNnXbBXUuYyvCjRrJhwpPWHPBbDUudzeEZoOgGVEEaAlyEetHtThHyrRMmYnFEsSsSuDvVZXxFlLfzZiIzbBboQqQqyYOkOgGoKcCvEFaAfyYFfGgaXxlzkKvFfcCVCcnNIRriGWwgtvVjJyYOjJgGodxXoFfuFfFbkKjoOJBbBLloRcCrTtEejJBbUtIixbBxXQqXNnnNzJaAjZCvqQVJjcJxeEAaXWwZsUuXxSOoqQLlcCeESsVvEpPkOIiWwInNVviIiFijJtTuiIGghHhChxXZtTJhHyYjzmMnLydubBEchxXxpPXIVkjJGgKviWwqQipPICcyGfFgYyZecimMFfFeElxXbleFfQqEPpsrpPoOwuUWEgGeZzXHhxEeRwWeEulLLlUnCcPprpPjIpgGPpPxtTXVAajJZSsVvzzZYyqQxtQSsqdDFUuTlLtfubBEQqoOFfpPmMNnneHhJjEWxfFUuLlDdXwXxNlLeEdvVSsDtTEaAzqmMKkZLXvHhVEeYyxLMmlMfFfKkiIKJjcwCpcnNiHGghNJjkpPOmKKFfcCqEeTocCLlCcHTZVvaZzfFbBbmnNOXxfFoCLlqQIiJuUdFfSsFCcfrRrRDbfbQqqQlFfLTHUuxXUIiuhtIitTNnMpPfFgGbkKBmKXxevVJjlZpPzXnyOoJjDdwsSWxXDDNneEdoOJXxjNIpPsSpPOoindBPplLSJjsbzlLZSsMtTmYwWNdlLDVaAvDgGdGgDbPpBdAaxPXxpXZmMjJzLUbsSoLSsuUlOgGIxXnaAOofFlLhHNijhLpPKxXvVkCIoinNyYDdnNNneEZzIOSsIiCsSuUcwWpPMUlsSLuRXxrmcXxCGaAtjJrcCRbBcWwxsnZLlxXBbmYvqSBQqsvVexuoOLlMmUyYXWwXxSycgqzqEeCFfcYwWTgGcmltqQFfYKkLEMyYoOmDxkKrKkomRrrfFwCcsSmArdDRlRrBNOokMXxZoOJjxXapPAWCcwGsSZjRrEwWeEmRaArPoHhHGgJjaAhNnVkKyYNlCcQAYpPyazVJWwjZwvbBVWbgGPpxXBzBDiUuEoOKcCLliIGwkDdGxXZOehiZzRrQqAaIlwDbtLJjUUtTFffDdFUuGgutPaRrAPGgtTzxzZlLXSmMaRrQbBnQhmMrFfRpPoObsSSKnNuXxbBoLlOUNxduUDXnTfFeEUurjJaAjJjDdbVvjJBJGgvkUgGoOzbBKoahHABbOoOHtTLlNXvVvVhdDCcnvVsSFfenNuUbBpPZMmuUVvzkMZfFXNHhnRrvTtZzLlCOTtoSsEeBomzHCcsSqRrQycCOBbMdmMiIxiTtPCeEyyYYctqJrRQZVlLvrRVVHwkKaANIiAaiIdDdDTfCcFtnWtmkKvRrPxMsSmSYyWwgFVvfvGgVhHKkJjKofFOvDJjddDfFByJjYMmbOzgGCcNrVvPorXPQYyzZunNUoOqeEeEVvpHBRYyrzJjZArxiITkPpKvevtTQqTiIYyCdDdhHDbBbKkBaMmVDuUdMDYyEWwehHgGtQpxeEYRBOlLokHNnhMtTpPXlLxRGHWcCdDwJjSQMmkITvBFfbVzbBVvReLlMkEKMmkNneKkKEefmyYIiGyYgMOAEekKkKWwBfQqxXFtBbCcSoOVvuUiIGgyYDdNzPgGpinNMRrmfeUeEuEjWwJUutFLlfQqpPaqBbjdBbxXDTXxFdcbBYvVoOoLTLAmMEeyGRzZzZGgUmMJtmbBgiIGJbBESsyFTtaAzfFMmOoEkqEeaASpCcPautTGWwSxPXOjJoxVQBwWBbkKxXfFMmrRWKRYyIiTuUttoOjusSURJjrJPpqQDdWoOoEeRrfFkyYEXKgLwKavVAxOoklLWwpuaAUYyPnKkPpUffswWouUclLXxIiAaiIuYIrRiyglbBLTkKkJjhHrRFWwEeStTznPFfplLNfCUucSGgmDdSXxWeEGkKgCMtXgyYVPtQjbBJqQNnOyYoCNnHmMjaAtuUSkNJpPZgGzCcuUaAWwjeOoEgGQqQqSsnMGkeEYyuUBOoOgGowWbiINMpPnNbBGnWcCcCVQqVvYVWKdDkOoQEecExcChHuUAhHaXzZLlWwlMvfjJhPpHcyYaxXKqQkBjJbrRpolKZbgGIuUiBbPpBgsSGyvJjgGIlAaSewLbJDdvnGgaANVhUfeEaACKExXfAaRqAXxItvVTijJIUZzucRaAYqJrNXlLxYypNVMmvPjJkKpgGVvHSosSTzZEzZePpIieEtOaAUKklLFfYcCTtEyYYyOoIXAaVvvVlhHgkKXxdoODzZGgCcGLEeWwnnNEzPpVmLMbBqxxXIEeFfiXtkLlNnVlHNUunzZhPpgGHhZzwOoWTtYWmGwWgOnAaNzZomlLwewDBbOHPphewZDQaXTtHhwmMWqyYQLxXlweEeEWMkKzzZGgZDdvGgYyabBaAJjEeAmPpbBvXxxXiSKVvDdLxXlksDdnNcFPpfRhHHhThHtrCxlLcaACopPOtnNTsSnKoVvxXgQqGxXKeEtwaAXxMmvpdDtPIipXxYWwiGYDdWwxnUugZzpPFAcCPpXbBXWwGgWWdSswWDSZVnZzjSEnNRuUrUdDkKPpCcFfmMKYEqjJjzvSsVZPpWDddrLlgGRqnNQHAaAWHOJjBbNcCsPpeGVDaHeebBEuUWwuTtUEhrRgGwxiIXxFfpPOTtAaocYNwWpPLlEnYtNngGEetaJHvvvmMUIyYuPFcYtTBbAaGgyYXxZsZHtBZzUuIiaADdUxbBXrRuUPlLFfWwqQpwWLlTtgqidarRRrdDZzAsSgADvVemzLIuHhUsZzHhpPoxXvtTVrVvzZvwfFXxWmMtTVQqBbGlWwLgRFVEWwIaAjUuUuYSsyleOodTbBQqKkRwGFfgGgSUlLMmuvfFVyLlqQAaYoAaSNnOZWwjfFPFXxfAbBaaAZNnSscAQJjNnkKqatTtTHhbBhWHDdhvZzVwHChHhMSsmTXxtYoOyRTtrSFfdbVsSbfiILlSsQjJzzzoOZuUZDOOTfyZziHhPpcCIxXPylwWpPGgVvQjHhLnBPpbNAFfVvOfFoUtGggGoyMmDdfZEeTtzrNnoBsOCcjsSyfFAoOLllLasSnhHNYDdbBJCcoLltysgsSGSsaAaAdTyYYgGVAaBbuWwUvsSAQOdPpiIQoOCRWwSscCchYXxKkJIiuUjFtTGgfHhAaJJjlffXxFZzFKkKkitTqhHvzZYyBFfBLlbewWmWNnwFarRigKkCcNnybcuMmsTtqQfKkuOPpnNoUaANnxUdjjJVvgAoOkKfFaicCXUhHsktTbBkKWkprhVOobBbBYqQyAascCSbQfjcCJFrRbEXxeSsdhAaNnseAybBrRLlYatHhTUCceDdEWIiRaALsGgtLleAwkKWMXMNnmMzcCgGJjaABfFIiPpbRDvVdrgGUZEedXxxXDMmFfvVZChHQqaASsQhdbBDHSsmlLIidqMIithcCTtHklLjaoOAzmMZVvEeJQcCqBbDdEeLNqQxdpfFiIjJfFsSBzZqVvQwWmZxXOozMSfSsFtTxXXYlLdDsxjrmDkKPEevVplUuqNnaApuYysSQyvVYZGXxguIiqQUuVadDAxXLlcTtCvCcAXxXRrxbBzZQqCMaAmMzZIiVQqtfFyYazZALXxwFBMgdjJDCFUnNuCckKdHEehMfDdgGVvFzZhHCcgfuUZhhHHcTtIixXcotpUnNuPYRrnByAZzaYmMbNnKkPpLpPlPpWQpXxuoOyMmWDSsdBbfFzvVZzzZZXaaAAUuxHhMyeYyEWIBbidDwwfXxeEEeFzZDRrOcWdDDdphHInTVvicCItylfFLhFfHYNVvhHiUuAazZPxdDMLlBgGRrlLbicCIqrRDdRUutTdDlXxLcqrRQJRmJTtrOotTRtTVyYWwKkrXqeEkKGgWDdKkLlwPCmwcmMXtTpURvQohzguUJjGguUMaAmGPpZczTTttZkKcCcCevVOozZeECcEQuPPpOotSFhHXnNGgLlGgdDRrxrhHZteETzGgoOSJjktsDWwaLezAdIiDJRSsryvVBbjrtTiIQqRNnluUdDCSKTtphYyHPuUDXFNnqQMmAaDdGfLlyQgGEeOokKXSBbVvgqzLlXxeEIPnNctTFgGzZfbNnBwSsWObBoLlwWPIiJjdviiMmXSsXxxIArRPpaodDOVvpRrPPYEeybBqQQqwWbimMWweEVrRpPSsvnoOTsYySGxbHhChHcBsoOFfOctTJjgRrGCoKkOZPpFfUumMCiIPhlLOoHMpYmUuRpPrnNUyFrJdDuUmVoOqQideEnJgGhFfkKScQqCsugGNLlWlLDddoODOoNUunntZzVlkKOUqQuUJjEecHWXPXwWkijJezIyYsOrHhUuibBIqQRogJjyOMNuUaaAAnmryIzZCKYyQlJqQjeFzzZkLaVBzqQdDzfFAaOopPxXZDduMmBiIFfbUyBbuUYHzvVbHwCccHXxhzPbBWbTtJjDdSbBsBXsvMmGgVrRogRrGOZOFMKkpPmfiImMokVvXxlLKzUlLuCepPLlZzuTtictxkCcKXddZzsGcCVvWwgcCyYAanNOwWSRrYyVvaAVqQhIhEemMHOoxbBcCrCcBDOnNEsxtMmTGgOpxDdnnNHeEXuUxVvmMnNPphJNniIhUaesSNnNuiTtIGZeOgaAmXxMzZdLQqqgGjJsSeEOzLlQqbfTtWwxwhbOOElLAaYyHhzZJEOIfHhFFfgXlLEfiIaTGTfleLxUudyYDXJjxLGgjJLzZcuUClktTpPmVvRXDdaAnNzsSsYyGgDFfvVOoVvmXLAvUyOodpBbDdFNjJZzLlNuUHiAafgjTtgGphHsjJSPHhIFfFxXRrwcCmGHHhhxPszZZTtTtzXxxbNnenzBbOSsOoqcgGvVpUlSsXxfhHFCaAAanIioiIOOCHhczjJZCchjJHcaARiIIirpPrRhLDqQdBvjJSsVqQrBbxTtGcCQqgBbKkMzRrJjCcfTqfXmnoOSgGctJjTsZQSsqzuUvVfxinNcJjxXcCEeCKkfqQweEeEZzfTDdCuJjULkciAWwtNnuUAKkakKgGbBpOoPEiIcCoxrROoOoIaFfuUFJjGoOgErRMmycCXNnxKUueEtFfThUuNYySaAQqjJscyQqRrQpBbPlvVLsSsutTLFflthHSscrRCTUFfkGgXIpPOoxqQXMPFfYoOEDdebBgGlDYyhHAabrRBbZzBjJdDeEIidKQqMmcyAUusSSbkVvtGSsIVbcMmvbDBOohHbdXpXuUKCcBSkKRjnSsNjqEeqeCsSRbBpLOBLlMmbqmmNngoOGOxNyYniYwWSrNnRsyUurxxXIiVvSsJjKOoiIIixMZzSpsSuaAKkUMQFfBvVbTtRrJVvAaYttLCclhQnNqzZeZBAaEcCxXebzqQdDWMyYUuySsROzZozwNnWZHSBbhqtTQTOEduQTtcCGkTtKQqDdMkAWwaHhfFZZzeypPPpBaAuDdiUuaApPfUuFZgGzZPybBSsYXPDUuEflLdDTtFkXxCmOWwnQqKkpuUPfFNJvRlmnWIRiEfFdHGdDIictZzdmMDnAaNrnNRSjXxfFOoULlnNnNubBkbFvVPShuNnUuvuUuUREbJjBeLlYydyYDNUtTuCcsdiIDyyYYEetTILVvnnNzzZJJBbOjJeEJMdDsSBIuUdriDdfFXxOmPUufSjpbBPELzDdRrOYyoJpPOxQBbVvcHNcVJjkKosSocCOqQOEEeeghHqQwLCMxaAuUXhHTfFxXnjJNLVaLjZolLOzFTtfyYMETtuiFEeJjlLevVEbspVvPEEeeESsgAAaSsaAlzgxFfQvPPPYnqZTtuvgOoMGBbFfpZrRzHaAnwtApPaTriSEehBbWRrwbtWAaEeSsTVjEephHdpPIiiIuExeEQusDdRXeKAacCFfJFyOoYQqFfZzrebiIuUBNnEGgSqbGguUTtBrRfurFfRUFsWwOLlOWkKnNTXJZfFCczxwWXiIjueEdlLDBPtTpgGTBMmlLtTuaAjJiHhFbBSsSwtTBbWLlADdmMxkKDekoObBQqKCcJClSeEdgwWGqogaAGnPphuqCDnNwbmMBWOyYoXxfjasGiLlYsSEiIerRQqeWwEvqQfLfFlFaNCREjJaAgfUbBuqQIMeEIoaAIGgsjJSiXyCnjWozZtWwuVFfhyEeoEnNfFHAbBmvVQIikWHhwAGMwWmKkPlrOoYyPpBlqeELlrRGgQDeUtWzZwpzZPcCEPNeEnpeaJjkrwRrhvVSsRNnxsoOSLmZzMjptTOoXnvVaAcOlLAlLaxRrFfpkKPXQqmMBbxYErRLobBOuUeCQqAacTPptocCObtTBgGdVFaKSFfmMkVNnMkKnkOoVvKfQqGhBbHgLletTtBbTElHhLkMxQqXaAtWwAmKkAmRrMPiaAIsSIPfkPoOBbivMmOCceEvHhHhQJjrdNnwEeDCcoOdTtIYwWzMpPGNngmUuhSJjsvVHhwWnqsSfnNzEBHapNGgJNzZaPpLKVvtIiWVvwTeEwxgtoYPFfXiIRnrRGJjNaWGgsQzZoObBYCcOEenbBMQUuqVvmiGkKtTuQqUBcCaAVvvGgyYGNnvVzZeEfOJFEVvOoPfnNUUuuCcnIjJGgeqEeSZziiIILlFfmvYyqzRGgIyYiwWpciIRzWoMbIBbpMmtzZMzZybFfHfRZLCLlPpcThqmvDsuUSsSdKkXDuyZzSshHYUdxuUXnNXxYxkKXFTnDJjSjnzZbBtPuUcfVEevqyjmKkuUeWXxwUWnNphLlHPBbpPWyTtJjYwwuExqOohDdHvWwVZzRrIPpEwOoLYsWMDSsdpPHicCuITtndDLlNiVvUtsxXlIRrHukKyCcIiMmgGsPpvlLDDYydbdDBbFfWwDdfdqQZyTtlLcaFfqQNnNeZJLEdRdsSJYkkKrzMlHhMksJYHhyjSRrEOCcNnNXOoihvVjJjuUuUEWTtwfMmhHaUurBpPpPkvKkVKbPsSbTtPitTAsSYwiIpPFfMjeOoEmyYMttTTXQtTMbxzZPAatyYTFfpUSHhfFrRNghHGnpswZTXAnMmVMmvFZMRlLrfkKZaeaAEAZzOozYbuOoUuMXxmgwyYWGUqcFfmMtqQJZtTFFfLBjQonFcCMmYtTyfGIiGEeDXxxfFPEepdDpOqGgnRpUubDdBzZWwSwWsMmTVvtCcOojDUupPMmLIiyaAEneEGgNTwWkwWKQcCtTCKkccJZfFzCRddwuUWNnjfFJDNOyjGgXxJmtTSBtNeoOvVMZExkKXTOkKowZiJnUueEZHHhpiEekKIcnNamMAqQCyEeFfYgGfIUuuiDQhHYTJjsljJLRNUJjvkuQqUKVYmMOzZowGrfFBgGhxXixkKXbBIHJjaspKkPVnNxGgXHaAGgecCcuPRsBQqkKQVtTJjvoGgGgyYHaAoOhElHhQawWAqhMmDFWbBKkwmMLloDFrmyPDdrdbBWwjJXxeIiXxHLlcCSsiOoAaIinNtTSTtSsaEMmAapjJWwsSPYyGvVdAaDsSKPgymoWwSHqgANnaHDdvBzZbVqQhtTsVTtvcCniltVqQOorRdwVljhHkeEYyNKknDNndKjrlNnPFmMCPsYyZzhfWBbqRrQHFfhARrajFGgfWncgSsGMJjRrFNnkKvVqxuIiKSRnttTAyYaACcacCbgBSsVmswWOogXBvAUuFfaYinNNdDnOhHUuoPplILVuUVMLlqaNAdDaIPLlanNAKkkKDhZVSsrOHxtToVYyviZMmpPIzZIRSsrRLmrDdBbcCDdzxXfAaMmtJjTDdyAbyYTuUtCLDwvXqRmRBcfXxMqWHhwhlCcCcGrLASsjJbBVqWuBzWkKwZTghcGgJEecCYymESCcGgsQqKdDtTocCOvLwoOwrRsbsZgDnNcBVvTtnNbqKkpPclIvPCNIbgGDJjZBgcwWCcLYnNxMmXPpPeEZzpXUSgGyYTtPzZbByYMSsLoOAqQOsSSsIFgGcdydDYypPYMrRavJjiPpbBIVFfNfdDDDkSsKMRDrbBRdrzZtDYsKkkTGKlMmLlLLyYIiRrUmcZRMUuWKGgQqDdTtbbBBgGcnjJkKcrOohVvsGgxwWmjJIMmoOWGgFFMOCaAYygRbhbxXDdBHiVgGBDdnfqsSuUQrcKQquqQgQVLlPpvyxhWfUuDrgrRGVfFBPpbeLsSoIiOwWYxXcWGdDMwzoOZoIiNYyJoOeaRTQWWwdhHGgTtDaOoMRrvedrCcRrlhljJVqYpruWcvKkVDCUtTlFneEaAdVvHhDbBDdjiIrMmRDLlIHBbNsSnKRKkrYgdLJfdoTmrnFfdqQNdDBtCcIieEhHTbxzZXBTGgtGnNpwTcavViIeErRHhfFQqACqQEsBbyzZoKkuZzUTbuPpjJsFfBGgegbpiRrSMmPpExqQaCcbPdDBbZKIgLwSilbubBenjpPCcWiIiIoOEejDdJIdDfLXQLVvGgzZEevoOVxXsmtjJTMqIdDkKiQyEkKoYRrykKOViuUuFfvOoAaIiCyYgEtFmUusSMCKTSZGgPGPpgpPgGprvSsOoyYohsvyYghHXxGPpXaiRNJkGgKhXxDdHEzQZIiJjzhfMwVHhVdDsMlttcskKfVQqxxfFfHheDbBoOlKVHsfvhFxXNvVRrJiIgQqGXuWdDYyrzeEoyYkzZSsbVgGSMmXxcCFsemwWdDATtZzaomQiIqvjJoOVMORNxlROoOuHhMmTxXttuZcGRrUuSuUCLlcfAtKkTlXXhfFHyYxUxpDdPXgqiGgbBnCnNTtjoxGgEhqQHkKjUuqgYKlQLxkoOFjJBbqfEBbBJdfFLYMGgmysqWVvYyMpPmXxilLYyIDfFdOaAoRElLYOtjJToBbkOoKVviqgGQUsSueEmlEzgoXuUxSjSCcjPpTsJfFjmNnwqoRZzeEhiILlfWONPfRrXXeLbBqNBbWIDdiwyYaEnNzhvfVMHhmcyRyFqBbUlRagGHEhQsnNnNSAdEvrRrgyYGhiIehnqQdxgZzGPpjCZzhLiTMmpIidmMDPsjdlMmFsSDqQdVvoOObwjhdslImMgeCTtZJVvjfvqQXxmMglrFrdDwtThHQqWSsRnNZIkSsIFnzGIVMfFmvMFfmFwOjEeJKIFfIYyYxXlLmMGElLQXNYSSsIlmFfDCPtbAaSsdDBTpPldhHDMfFUTxXCcgcPMmHlhHDOoPpdtRrTOqBbxfFXzysSEIfvumMIbsQqgLYlLBbWxwmPpMWEKqQOokKkUqQueffyMmdDVLlnHhbDTtaALJjoOzIOoDzZfFdeACFrPpPpSGgxxbXiKkGUqQNPpdkKRrSstFnNzZfcqTaxOUuSsCcVJzMmZjvqQOVlLvTtZzoAaBbNkaTFAyoOyYOgGsPylRrLYploWwOLSfPjyjLaAAuULsXXxuUPQAaqpMbBBOPSlxyYgGsSlNXxnDFmdYTdDtyRrDuUMfTtmWwNnFxXxNnwRpeiHsSGpPCcGCcBbgdTtBboupznQgHkKDrTnwQpPzCqeDDddInRrNKPpkaDcEuUTtWwMmkKsGgSgSsGlcMkSLaGgfAaDDdfqQLgZzGlwWBwfFCQhvYyiIwCCAaerREMmsZzSISXMxgGXImrgxjJnPdDBInYBLlcCrReETNyvcCeEVmGhHhhHHgajJBbXPKXlEVvlxkDOoEedKNnHHLWhWNIiHXqQScdTGUNnuYjJyCsSsyaDkKEepZLvFmPpnNGdDggZzxXGHpYyPZzGgMwYAahTtHexYlftVvTCccCUhtpwzNnSoWwfTgcCGKkGgizMyqPkwWpSJjxHhxXZkKUwWJCcsJzZsGgwWxPVqJjLLlckkKDdKCQqgjJTtdXRaVvrimSfFscCMItlLYDpPSsmFduUUQqpPkvVHhUBThHuKIiioOWwwWpUPPppRHhrRuUoOomMzvgdDGMmdDsSIicKkuUTtlLtTuUjGfapPrEgnNkkKxXlOoljJJQPHPpQPzUkKUbgGBTvBGBbggFfqQbBMtTnUuNyYvjiSwGgJdVTjGFYyYyfrAunNUEelLYaAAaoPGzZVvgpOFfrbMmJjEDQqogNUulVvLnaHhyYnSsVrRhPVaIBmMbiHLtTomMfhEebRrKkwwQqGTJEyYbxXBwWYWwRrHhzPpIiZhDdeqBSsVvNzZQqxgtTGowazHhZxqQLlNsSGeaoOENneOzzZCqvVxXiIYyagLlbDvFfFfeEVWwcCZRuXxFexfWNnwpPPVrZPiynRrGZBiJlktTuUKLQFjZNYylLnwEeOoOMkrgGJgGAsZJjdDlLDjSsJjJlBbwpojZaAhnnNrzmMGgpPWqhZzrRHZKCcnmMNIxcwWKkCnNpXxkKnuUdDNgbBQqjcuGvVDxXdoOUEetTVvVvFVknoHnNwWGgwxXLjaAvPLlBbMtwxXGWwSijJvVQAaYhugeZxwWdDjliINnZXxHfbBjNqrfWwGgzWUuqQhimDdppPLUmaAFqcCJjeTroDdzZjOccRrCtqQnOokPDdFHxcCaAQuUoOqvSsYdDvTtVaHMmivVZzvCcVDoWQwWsSqiIwgGVvcGnEgGaAeEAaeGgQWwqJjzwlwTKLYcrLOGsHhGKkgXxUiIoCnKkDdUuUJjkNEcUUuuDdaAOGuUuUZesSEZtTzNVviIyclLCYhVTDzdDWwAnODXxAaYyfFdommiIBbfFntTGhSaAPIoIigRrBUEejRrxoOGoPWRrxCuUFxRvdqQTtXxNHuUFQlQZGJTiPbBbwEeWldPpxeyPoQHRrbcCzZjhHJusTYBpPIcJGgjvVicCioJHhzhHZjXuMeEKkQYtzXfFbBvBnvVPWfZwjJURNKksqQbTBtIiTbPAaDdHQqSssaAEeTWwdDUaAIiuzOvVVTIijmMhHiQKktTGLBqQpPHIiCcrRpTGnvqmGhccCCHjKSwWwAgGoJjlMOooZzocBBLlAZziWwHhIQqSdDbBHlLsaCEeBnNhEzTlLNDDrRdtnNTnNHnujhMmVvLtfFmSRqQqHnNJyosShHNnnNNnIWwqSJjXoOxemMTQqtvVTvVlWnNwLtquUeEbFBHoOEEeVveiSsMBUMmWwuYOPpBboARrzIicIjnvVSUUkKuuxXxXIQVsElLRmMbBnNeEhHpjJjYDpinEeSWQzPwFnDdtcpPCTtesSIiFrEBSslZyYxUuXzUCpPtTcRQqrJqQkoOImMiUEsVviSxBbXleEqQBxXbNnqeENnfFRpsZzSsBFuUMSsiGLxaAXGghHAJoAaepPEJevVayYaOoFiQqIeoyQqYwWOEfAaGgJLlTbIekhuUzXykhHYtsqTtLlgJjEoGgyYDbBbKIiAhZnzSppPNoOFfGgeXEezIiZoaAfstSsSFrxHhfFYnGgFBnNcCGgWGCinNAlKkvEeufPpmrRMFoEeMSsISbzZQqDPcavhQnZzNSVqQcKkCqOoPaAwPTtGgrRpdDqkKYoOyQlUgRrnNGifkEKBbkIUUuuSsSGIisVvSptZwWzTPBpPpTtRBbeEyYEEeNduDdvVUhfBbpPtTFBTEetzmmRsyByZzswKkmMjbJkCGgFfNDdnrRWZHRrjJxXxXiIAtPzVvoFeENESbozKVvksrJMwwsSWXxaAhHwWBbbBzMDdbFsjKkqQCFfcQqkDdVvrWmEmbEezCJQqjxTNHucrRKuCOoWwXWwyKkKkDdCKkcdDqQtZsHonEeHhzVvZSqeELwWPctEeUuqQZqsSsNaPWbztvDPpdVmgyYGZToLVUoFfOvRPKtlIxXiLoFJjEefibBIjYyJFywSsfGVmtnNxXKknLlKkDfOrFfRgVvGZGgYRBaSDdfFsxXFRLJNnFwWzzPcDhqQHSzmYVOoUIicCUuaADzknZzKbzZpclLCoORJjlpPxXxbMmuDDmyQdKjJgisEZejJEKCGecCVdDvYiIfuoPpnxXTIiuUvKxZCcCjJcpnNedDCcbBXCMmcqOoOaAkeEKejYpPaOtTcCRruagJyywVlLtpPKkfkxunAbQLxmMXJPKkHTtSQqYcylwWLYCaJLljptTiRPEWuUFbWjndDCLlFSxqbydoZvVHRCcoZzHqQKkRNnrHMBDdHhbVGgwtuJjOwsSpPgdOWNMmncIxyOgqBboRrinEjtYyCgGRSneFfJnYygGNPMmyUwuOoaxXGHsgGShttTyfFfYMPsHAaWMmTtwhWwTOHhIrcHaKtutEMmeoeEOHKLQVbGWplWpcsapmMJlLAaQvjOoqGUUaAddRrGGEeKkyYgxxXCcDdXWSNEeFfypqzedDmFFJdqHxsSYyaAyEeoJjOknyYdaHEqQxWlLxXxXyZwJbBjiIqQlLrQGPxXYBbAaKkypAioahROcAabTWOonQovVvVeEFfkRrbBspmMgfFauleKkYyCLlUubIyYTwRtgXCHvVngGFuUvzZJwuUCtUbJJjnNjYbRLbPGZbSBLlbUuObBaAwqQWSYRGFwxXKtLxXllIniIBCHmBBMmEebbPHcQqSDtTMVydDKqQDdVyYMmQqrxfUStTnBSSsJEejsokOxXvVIDdDechHUWRnkRrBDcCrRdPLlUBKmMrRGoTtWkKLMmuUhnoHhKzSKpPDdczZnbBUCmhoOmMHMkKcEiwBfFEexXcCjwzZStAaTsGKFfkWVvOsBgGbswKBjLXixMDlMmoOPvhHWPpwIgLMSuUBIqUjJsSQqlsHhuAaQSsSfOadsmlLYRvnNsSVQUJcCmymcCJtThHBvVhdOozLlRldDBxsSPtGgfSKhELKkDyYmSFKkZxIAaYyzJzjwlChTtpAahKbBqQlYuFxrrfFiIMbBgGsTkKcCFfaoWEHhnrRxQqXNIiesPpSkyLlkKQqFfFMSuUXsSyaBNiIIknJjuUNPQIiqHeEdHDXsPkaiIlLAKpSsOoSxwFfWhHMmmlRiIpLaZRraAlcCtXzRryYZSiNnCEqGNnPBvlLVgGUpeEPugGYyHxxYfUKivNnVFWxXeElLnzuUqqwNGBbhqeEQHwfPjBFydteETDuCxXcsyhUVvPpWdLaPEkpPgceFqxXRLleAaEqeVKdXxsZplLJaxXAycNaOwxnTDdtUHYIxXvVMmEazjJxXXgGsLfJAUvVjZjItkhHNYytJjAyYgGxAmwKkKuULlkpuyYUPnCcVvPuWUtTkjoOlFwWoOEQHhqeBevVlPpZgGtoOTzRrMJcBxXgyDdYGWhHmjUudrgjgRrBFNNzZndzZEeDgGKkquJKwNOjdEBbqRXxPpWwEeWwQVMmdDMVFNzwuZzFfRXxXxvVHwhjjJqDDdGTeEtzYkxDnkrnIMmFgnGrEQqepwzpPaAZSoLlOGvrrDcCyYJxDQiRhHaZHZRYyEVvppPmMmIiFJXsYypPbBSrRqQYJFYyBAaakFfHhZUqoMGXxdMmPpPFfGgJLlbBfFjENCpPWwWwpevtTgGoSsOUmMunvVNnsovVWyVvYeCdmzFefbtlLyYsLluhAhzZnCcEeJPpzUEbAMWCcTRMmYyrtsShdDQIiRQqdDQMmaJDdfvZNnzVtTFvlzYyZoOYkKlLsjJAapPoQUVvhHuquIqQiUZzfoanNXIiFNuJJjxXeEGwYTJjvVhZzDdPUqQrBbJshMjJyWwokDdycLkfFKQSPrxmcKaAkZzHUvFcjqIqQMsJcNqiKkZAaMRrmXKuPLnNycCLlTtGVpTsStytqQTYERqTtQKkPbBmuUMWiARDdbZWwzMvjPpnWwgLpOwdDfOwXttkehNNkjJhoYaMmApybBBqQbCcYEiIvVfmMNnFSAaswtTyqQXxeNCPVvkVvfZjJMmVFPDAadilLIOKMmJsYyYyvYKIblXVEexXNQEnwESjcxwWJPzbSsldDZzVNSsnkjJdDdbjTxXtsSauYytNnEOjUiNqQjiIRGgZzrJGWwgnoOnxXNhKksSrmmMVvZzTsTthTYNkXxcRrCGrCmtsSTXkKLmMlcSwJbBgFHxQuhXxyYHrRTaAuUOoGeEkVgGdBdePHhpEZrReEGwWJBSrQqvKiJjVvByXxuDdPpUowGHwzZWhsSHWrRncsSiIqQsNcFRrfWhEtgLQqMuURJijbGgsRAadCceEmMACDdZDBbSsdmCcXrSypPdUVcCKKIYMEipHaNrerXbBbBfKWhSscaJcsoOqQfFVkKyYAOoqTzZcuqnNPEVWXMmcGghHDkTJjaUucicPlLdPpDfiuJAaIiiBmdpPpDdNyYLQmocoOUhHEUBZTXvwLiICOoqQclaAHtUgGfKkYPprlmVbByhlzeLPpWwSoOAKaQFSbedIjgpEetTMxFbBZbSzNDDLMFzCrRBbxlwfpmMPeDLggDgswlLWNnopqQjJPoJulfuUsSbrRBeECcFIeEOZfnuUVqqQpbBrpKqsjfvVQUPvAWXuOseEeWwEIqcCQiNnSZoOIIJSpAWbnpHhQiHDgMuURYTwFhNnociICbBatWMwWeXxlayYoOGAyGgZBhXkKvdoUkhHaAuSsWwUXxpoWwOkfFKYylYuUFfZVSMmQWEmskKhpPqQHSpPWGYyWfMiJjQhlxXLCctFIpLZrHASsJzShlNnuyYrJGGggssnBaATeEtuUGHhvspIjJiRrHhPSGhHgKwOMmoWdRrvBbmeuXxULliUttWjJwTfFOOookbBfAaFlBAaIiIbBibMzZzTtMUoOlnNLuuUhkAakkKKQOEzvVNZzIyBdDWwsuTtcjJFRThKaTdDOoSstUMmMmtTGgXtmMSBeEbESLvVlszvIPpKkuLRXSsNkNxrGzAtmqGjLlCcwWYTRAPWuApEvVRqxtzyHhOJmtVkKiXNcCJJeEKkjmUqVtgGlNTeEBqQZBboOyYneNPVvfnNFYyLlSrEdzWwmtlxWvlLCqdwgBFXSUaBgqVvFfyYhJUIIzZbfSWfyJfFBbggGGOYmHSDhHwxkjGvyYcMcCdCNHhPpbFFfQqJyYxlLmMtLpvDflLQsuEePuQqUDbjUdDHYyIlUGgvmWhhMmiqQIZExYyCWwVvPpsaLXjJxaYBAEDypdXyVvYxZgiILlyaAFhelQPpjhwWHEGhHUuKGgtTzTjvHhXmMxWwwqKKkkUuQWHeBbtIxXWMKPTxaKhWwvKoOfFkZzuXVzldJDzZjZMchlLNnMmJjDbVbJjJjHLlhZDdYyiHMSsZgkCHqvVQsShUJvJtfFfgvVvDRrdEMPpmhOodXxVeEaIiASkRrevvgwyuUEeYWyuvVUhHRrhttmQKJDggBbjJSgGNnsYyIAavKOoYyYHhxKUukpfHGNrRnIigOuUsSaJiIByFjmVfYyFJUuIRyfxTikqpKpzAWRihDbBEedvgvaseZNAaoVoDWtbRMnWxXpYaXOjtTbBJyRrVjTtmiIIpVZzeIiEsBtAoZBbhonFDHEeXoOgnFxjWGAqHhKbBkKkjJqlXWNGiIZQtTcvJXxlLUJkSxYwWrRRJhHNdEMwWmIxQqqUnpPQhyFfYsSjpvVoHeEhOPpneEaarmJXtrxEjptAaTfEUHrRqQlZWEewzRtnzISYKkyAarjzeEQqmhHZrHvIsFiIfGPhnNvHmMZUxaxyaOwbYKnTqQYrRhJwWCcYDdoOhQMVvOoLLPSzXxvxBbXVrRnxZPpCsPYOqQGzMcqVzSshNxOmebXxuUMZbphGgDuUdZIizRraMCIRrPpicWwCnZdnNDHhkigGbBmtJCrhADhMoWuUweZzRrkKxXokaAZeEqQPRCckAadSsDKLlFfvrUnSsREeDddZzQqHRZLlEgaAZJZWAYyaNhiINaAWNnBbvLCTVcYykxfMnxJrQiISsqRjmXxUbCkCcVKoOkmkispPSWQwJjbbHGgIXxHhnWwXxtTCCtDdzZYyTqUMmolTtLIiXNIimesujnPpNjfgjJBGgWwNWCclaAhWwsSktTohKBKKRhEekYlmMIuZzPdwdDmtTyYRrYYybBTSsflxFfJfUaNnKGyeTbDGFikKAKMWdWwNWQYyRQjjjlwKkVTAJaLOdkKDTFpUaRkoHhJCdDcyeZCCcWONTNntnytBToOvuTDIECRmXxMXzPlLptHnvjpJjWwWwaAtrRAjgkeElIApJujfCSTqBbPpzZlnNLkRnBKaAkZzbpZzPHgtTGQoOqByaMqwWxXQsEPCssvkuUHAqIQpTtXkKRrxVvhBXzKvNjWBbwJnvVVOeEKhxKApTUSajlLcAiIahUiWWwkKVzQpPKkmMfFXHzOwFCvVqulTXwWpaAKVKkqkKzEerPpILdhUnFUdDRAaALBJaQBlLnOSsrcCkeoixHsssSSTpsJNRrcHhBrJDdjRbBkKhiIDduUzybZTSrmMCDpRXxJjaZzArxOelAawvVNFUSqQeHxXSgzZzDdadbZAJagnmGgrsSgMUuchQCaqZjplCXxRrJkoFYYUWlpiPNcUuapoONWwxlGigJjGIaAQqbNfweKEKSmMRrVPpuFZdJhEdrTEyhwiRAauUyGgYvNxMmmMXSrWhHskIUyzZycYyCYdDsSYurRiKMmUuSwuURsvHhVnVrtTIWHYrRetRDeHqQjYyDIizfAaUPpvcCskekCcaAuUjJEyYWFfFNnZznBgVvLXWwnoOPACnpIPLwuyyfaZNnrRzAOKjoOcLPJzcCwWQAcqHCmGRIBbiCrMmRcgGMNGAjfFazZzBDAZYyGshEqQsufnWLEoXkKPdcmMRstZzzBYZHbCnjSPtaAShXAaIOEKRoNbqAjbCclarpmZzMoOPuNkKxXnfNuHlLDleqQEiRZkKQvxDdXkXxPlLxtLUQcfWoZKkNnhxSsKkqZvPpwIuHbJjBDLlnNdAsSaCJAakKQqAsbBuJMmjtPakQqXYeEJjyHDQsSqdkotTkZxbHvVTQwWqtPqiQahKVSScpeSIiGgPpEemAYbhJjNjJrCcjJKQtsqQPpcFJEeUcCjzpPZBbPawWiLAalLKGJaTPJVNhTZAaxrceiXxdtUVtbThHYnNoGgwczEYjOKrAuyYPDdftjJoljJAjatvWLJJDdJqrqwnmMmMDwmkaIfPpgUudBtEYxXoOgQqWwkAuFjDsSdXIAaitTrRLFbBtGgyMWFaAUufAaDxXpUiLyKHrkkYybkfFdDRrHOKHvVLlLcOoCwnqXxQbaAMmFfmMGiIvVFJkKjfFJJUSEMnxOuQccWIiwNiDXxdhBBWqwbBIAaKhHMDdvKYeEycBuJjMEeXNmdDFXXxKZzZyYPpDdzFNnfCvtclPpVwZzmUuMnHnwzjpPzGePGgrRpzroOhDWwrNuRVBbrplLzKOqQEDtTdOVzZvmHdaHRcjTgPpGMIKcCzNcmATtHPBzrRmBEMoXnHZiIvQCmZgoyptTBbiISczXNhHZspllmqHywWjsSNnHyQqtVvtTNkyBUuWoAYwWXAXuzhVHpgvHhVUuSCciVhRzDdMZJBbMtTlLmRsSsiZNTrrRLcChueFPqsQqSLlQGgJeXRtTGgTxjMRAAvHhVNTtSszZPJHqNuQXieXxMAcqQCamDnjgGrQqyXsKjVvunNjVCqzgnwzZxOoIvViLQeEmPpOofHhkKFMQdDqQagwJXfNGxUuhdfNOjJHiIzOaTbSPpvPpPJgGjiMIZziCwbkKBWcJvuLlUecloOLmMCEYoJPOPpofFpQqjxAyPsSwNmrCcYyBUuTwdOvZzOnzESnNAVLlGVrRHIrwaZPQqkPQKItXFYsSrDdijnNYyvbBMJUujJfYfyYFbBbjAXUuxooOhFJjPXykVvVWwvhHViGGdjkwWJjwoOWqMTTHmMOXxNnoYGeTtEVVLlZzEKsSsaAkKvDHbBUuIieVnNfvVFtiKkITGFZzqQvVDeHhEvVdTjcCVjSsucKGzmhIzkKBbBJjvBWwxXdwWrRpKkPXxRrgGAaHCmzPpsSeEKkJdjaASLlsDLZvxUVHkDdjJAXXxtPGgppkmwowWqQOibiIBAjJTtaInNikKTEhXxVJtZaAkguUTteJqHhLYyEHfzZYDdyztTZrRYGzFfnNyYDPYdeLoOlTMEemtmMKuUkabyAlASYycXezVvnNHHwMVuLihuJBdpUWwSxaAgdDGuUXqFdhVPlTXjfBYyjJPpncDmCVgJKXWdKkshMBbyoNnPpjYFwsFfFBSsiiujyYmPpMHpPQUuKkGbAusxfmMvVbSsGWDGgEeQcVwXLTMSsmMWsSHFuwWULlfhwZfFDeRsLMmlYEeypnENyYzbtnLHhThHPpvQuMiIjclLCnxIvBbTMjqRrQoUucCYZTXQqQrePaUwpOoartyIiJZzgQMTaYyZgRXnKGwWgnxrSsRrlUiVmMtTZexXEpPoJxXUujOEerResTxIiuqQAkHtrfCUSXxbYuCcUinZeoaXxAqCKkKkaAcgLlsSGzPpZKHuUoOmZmOoqQLxXuUKTjJuILlDdEMRrFfVDkVgbNnoONaASSjRULHQqsZjaMmUuhNJjnRzlPifTHqvVJSsjImFwXxgwMaAecCwqevtThHVEkKIsSisvzyLPmzZMfXfEeiIkEeKFxFKuODVxHbzYagRrpPALPpMmEcOHfFhoCmwIXxzeVJjvEZiBHhbTAOQBbqHfWtyrmGHhkLlKsSdhIqVAavYynNWwPNBXxfqQFwpPaPsjSVvsiDdwUuWizoUxwaFfVpuqpPFJSQkPRPQvNFzoiYyLUCiIcGgjOjJDjJWwdIinNORrwWSGdGLlGldVvJjEFWLXclLZfmqQKksSjjJJWwlDdHhJjdTJjthHfFYyOocCdnZjJsBzfBbXmZzSsPGJiDEBsfqYaFfAyAkahGQqaIiAaXxAgHoOoOEevVsNnZzuCcUlEWwZcfFHhCLHYeErRvUuMLRyFuTAahMmWVbaABdmMDxEetzbueuxXtTEeCOCcMqlnEePDMbnSsNIvzZVjqQUIUuFpClLICkKAtKcCQqduUCxxCcOoTtXWwwhcCHvepbDdBQUCzZtoIiOQCcapPvjJSCjACHwkFxRERnAhPIBbBGgbemyikkxXvcCuDxXYOQqKkosRxMhHzDdcfFaDuUrSvVBJIjrmlGTeHwCnSqMmQCNwAahgSsWOsSWQqwzZvVYoObIkVRsbjgzDNeEnYybDnNLlvJjkKTpPtKyYgtUoOqXhfGjWsCxhHMcRgBbKnRkKrrRyMmtHStMgGRsSHIqQuJokKeTbBUAXxJBDMmKkKvLthHTBZUplWwkKLlLAaPmEeMupjXTtQqCJstTeWXxNeqrRniIvZzWwVjJvxoOLumMlLUBikyVVvnNjKkJSsSYyjMmkopFffvzWiIwkKFKpcnEYWezzZZPdHhUuDyOHKyYnZznHoOEKTTxWoFWoPtBbTDdlGqQNJVaBbAmcCBOoraIwprewWPvgYlpUkxViIvzIMdDmBxmMXbQnBbCjShHmiQJCmMfqjPpvVJFfaAQHhVuhtTCMXRpsjJXxqlCzZYKOYmHSsSbBjRupHbBoOtyWgjBbUnfxAOFQqOSolLOyzvdDVZxXwWYykKLVEelLjAqrxXqHIieElLwmqQayYLlBeuZUxXuPJjxXpjEeNsSHhcCHabBHUSWwTBFEfZVvMDcvVQqWwEwOSmMNzZKkVENnaATtxXjJkzZTtJjJjKPcneoOpGguUGNngyYTtDtTYygVvbBmOQuzFfsSKAdTtDbfjyroORxjfjJMPeXIiqQsSxOoYkKyrzhXxzAbBHVvhSsrDdIqdXjdRRcCVgtTSssNnWhHPRgNGfiNRKNdzZXKyZRsSrgiIdDMtTmdQGgJHWhrAaMmUWZnfvmvqrQNWwneDJonWkjFfUQlLmLlMaArRnfbGJtTfFGRDJMwVvCcbCjmLEfFbQFfSsqndXxDNfLKkJKuwUpNQqmMsRrSWMaXaTnKTiJRrzJqQlgGXxLuajFlScKdDkCxQeEqZAeiyhuNsSXWouUUuBbAAqHhQanCSsxXYjPzSDkvEQrRrQfECnNGKepAlDwuHYLlAavVJjSUYfXsSxbJpFWgiInWQQZNwfqQyYeEIkuFyXXhHhbpgPpQWwecTtnNIdDsVvXxCcxTLaAKkuUHhyYBbzAlPrLMNnKkyYnNMiImdhDhFfpKLliZznbAYxEeuJjUsmfYKUuwOAdDtSHhmRfFRhHXfUyyYQVvqoOLIiGglLkXdSsmMSqQsDxaALlHXxYyoOJjrnoONRQqMNnmPHcLWJZxXjZYOoMmyiXzfsMKkdleHksFTpXFfbhHLrZDHbbBjpqQYyPJjMYMjuAaqMmtTryMSDAoFhjJHsCcZzqUSLpPmFxtTXfMKkCcuQibsmlGitTcCVRrpHhLdmXIxlJbtTnNkuUWrIjJiRSiISoeEeEwwWgSsWDdpPJbWIegGucCNCcCksTtZkRruUONHJjlwOgkbupPpbSsKNrwuPpCEdnNINnaAlLiivVoKObNDdEesuFfFdDXRhHvHhhdDHkTPptRrrRYKkvmdKJjkIvVisChpMhcqQbNiNnLTkWfOogrysoUusBpPzgpPpBEelPpmMrByZzyYBCcuXxTcgNnGWOojUdDVvuVfNWwhcxyYwWGUurRTrWtiBcbyYBELUZzAGvVCcPaeEAQqSKQqiIOqNwyYHhtjJBColLrHAOIaEegqRofFOLIilWzYwXenNhASqQsDNKrRXxsSGgiIYXhQDjffeEMEZQPYfFnFYyfPpeEFfswhHgMXqQxmuUjJqQGgDDueEuWwgQJVvvVVqjPASCPiIKkwwWLPwgBNYynjJvqDdlkhTUTNnMmkAhCRiotSYypmMdDmlLyJjFYTHbfFBhguUADdPyBbYEepYyJGRrgjUDdWuYgaAGpjrRJkhHKjENsrcwWTLlzZJxXDdJmMjeNIlLOjJQEeGoYXiFfCwoDGWWwoUTWIivKkmheEhOrhzOiIDHhYBQYyXkKsfNncxXNJwBHhfweprRqQIirlLIPAyPpshKkpjlqBarRNMmeEUXeEKXxFTvLlWYYLEeljGRrKkTtAzZUMRkKrmoAyJEYyKkmMoQxEHRrhPzXkJjzZKkVsSZztNOSsUFyqQjJEgCiIcckIizeSoOIGOokDqYMTtddHdDhUBvVXLrkKPXxRrxXBkNnNKZdfXxFWwuvVvEeyMrRZsdFfCpZRrZfuUhHjlrfpPApPbryznVvNWwoFdBbNTMvgFQPpLPplqWsSYfOTRrGglLkqQprVuvlOvVYyKKkgGktdDzyvVYMRrBbTZBwpAneEKkJWwjSQzAaTHhWwEeCpSslQsNsSOhSzTSsVyYhnNHyYvYFftTxcqQUkrRCUhntXBbUunNcZBMeEeMwRKkeEFxXfKJStTqQfBmvVZEeaAkKbBsSsSWtTCcmjRTtdNeEnDlLSSsIiZOBseGgnmMfOZBtTwWbpTNnaNnrRhzwumMUcKjBJtTWSIiYbRrYSrMVvPpMiIZTIitbeSvVsEHDnUufFWgGweqQrPRrbwWgUusnNHhiFaAfoOfDdFeKFWwFfqQsSIuxaAXLWpQdRrDvsqHVACEepdBsimOBbRrUNnVKkLBbaIcylLYgwbfNyXHYyhRfXxsTSYpPyFgOocCGBbOxEnPYysZNzyYzFfZHakBfHhNWqQNnwnMmFdOeTtGQSTyhHbByYDdKYxRrZHKkpPKEAVvaiBtjAAXxECcEejOjAaalHhgNMmWwnIgGmHhJjftTbSgGPrXDdxQLsISeujJKjuLHhtTfFOobeRfFVvfjJlLEcWwKkBbVvCTHhcCNfWpZqwsEQqyYeNIqQPdyaOoAJQqyxXYgGzZPreSvqiuUvVEesNJiCZJjaySsbFfLlMmmIZzwbBWLlsShbVvBbPpfBfFQEsKkQiONnfFYjhFfQXNnxHhrsFfQBbHhqzlLnNZMTlHVvJUNhtTKkdmLlMntEUueZeHbcdDAShsCcYycCvUfFuVabPpbCOwWuPpUOBbXxmlLLFfnNjJMmOaWskJgMfFNYynQVzZYRGgrRyZzYryNgHbBhhHtsSPhIiTsSVvtbCclgtTqIJtYyvoZtAaShpeEeEweEWtrRYyBSnrwWuWlLzFXxtTcCwpNBbbVxZTFXxFffyqmUxEkKeOIcCIOuUqlLQozZCYyibhHhHytoOSUBBBKkbbhKkXjJxVvquUOpYuUVEzBbSsZevEXDLBvVWwpZzItjghHzqLKkqfWwhMmnLlDVrXiIffFcXQqwpOQQFfqqgXCcnNAaJCcubAEeaCczZGcCOiuUDdgGpsHxXzZgNMMlvVIiLZzNaZdmMtaAvHVvnrOoRzgopPrRCentdDTKurfFNnRNcvVxXOuSgxXolaARCybBlktWLzZWiQqIZMmNkfEeFHhkKSsKgCRraAlLvVbJjBoOOdtTvzZVILlhAyVhHQSsqXhDdfJjpKWwNBbTKFfkcFfjvsSTtVJCCoJMmCcrRORtEQfMulPMIHwZbBFVDdvEeIiRQnJFKkhuUkKzSsLZLlzXxyYJQqwWXzElLGUHynNqIsgjDqQlLdXFfxcSsHRYyRrnuUNrhCtTJWTmFfVmWwMvpPwWpmNnMVJlWbBhONFfKvfughHWwUCJGEePeEXQwWqikxXzhfFJjHaAQwmbBMZBbRNHSsVvzJOPwWWLdzYySapPjZzRKmoWFfnQqNzJrRfqjrRgGdDrRIbeEzgNSsYIpjJvVzRvpFfFXEHhfUrzdBGAQckKZLgGloRrzFfuUZMojJOLlmAhHEgBbUunXKkPpAWOBbXnbQEHWwyYyeaAFfjtgYfFyWQqIiWBHnNFOlCchzZAbBvxXpHcCvURruNnNbBAoOjJGOdriIRwvqQZzVHhWpSscCPeBqQRyaRgJtveEFqynNCWwcYQfDjWsmMsSuNnUIJiIPpOoHhvVVxXmFfGbVtvVuuZpAaqhpqjLkKEAaeLKusSNgGnUGeVvRGgAlLFgJIiIisSCVvvVzZVZPNnpOTtlLruUUuuPIIikUbBtbuKfFunEeNDfluULLlQqMdyTRArxDXxGlQvXxKkpdDDdXSOkKojSjtTKkDduzXsPKzZpQYgGAamZIEeRrteEaAFYyOsZWVZWeEwzvVvcCPTHYyuFLyXuyYBbUHhWwEDdyWmIiTthvBoOQQqqYytTbVMiIfVlOozcCPqQkCcKdAYlLSuUciIgpPRrDdtyeTtEUbBuYDCsxXxtThuDdUhHYySFfsaAAanwHwQRrqjJcClNnhhsScCaIiAzZGgXLkKeLGgxkpxAQqMKkxXEeYuUBbntvYyVbSLlsiIyNibpSsNXNnGRMimxsiAaccIiWVaAHqcdDjJWbwGgWFDgGcCdLlSsdFAVbBvlsKmCFfLDdiIRrTtHheCdAeEdDiOodDTtMmUlLuEQccCZqWNtRZuUzfFOodyYJjhuUGqvViINfFZbjJBeEqQPnNUOOXxolLDgqQhINnEcCcCFfPrHhwxhHXESfCgDdGcFsjJeWWSsXfMdeEdDLXLspotTZzcCbkKeEmxSljUuJawYyWlrPEepvVRJOPqTtQpolGgLYmMJpFoyKMmYykYYaftEeAhvfFwWKAakVHKnlmMLoXAtdDQBBbFfbCuMmeDdEUSsnNTDrRnMmETteFfugSYysrRLlLHhlyYIotTOxnNYyBAaXtTxXXsREAaefcaEAaxXIiiaAZRbBrCfFZzcgGldBNfFLlvyYXxYeEFFEedDXwylGSBinNCcJIiCcUqOoQlLVvVFieYTtZvrpPBbuGgSsUiInNRVQZXxzQqoLhpqQCGthHumLpcdMLvVqvVQisygGmMnxWwqeWaAqQIiwgmMOdDoyicEeMmCiSsrRkUuovenNEVEeBbihZzvmMVHIrRRbBrWfjJFfigZNbiIBJNnjflLmMgfFtYyEeTGrRyYiKizfRLGVFTMmiItMmzcEGeEnNiLSGgDZzYyRrHUuAahHJWBobbBKkBFffLDZzJStTUcCpPxXutZGgLKxnNXkGglmMzdDZzgGWfFiVvIwBnNYyeEbIlHcWfFwJXDNHvVEHRJjVeDauChHcIiUqHehArLRruQfYrSKksrRYCvFSsPpCfFcgGGgVHZeASsWwnQhHlExaAxFpqQWmMwnCaAcmMEeoWwwFHEmMerOQneENBbKkWMStJPpsJsOGZqQVveiILoOTtZzMmOosfFBbkAaKSuUMrRqQGgIrvVIoOiRyerwfFQVPpvSlDjbjJyYeFjJQwzZhHIiWYysSfKXxXMmIYyiuUSsmLiIlMlaAqxXLgGkykKEeGzxXZEeQcvVlLCiIAaRxXrJKketTXyYOJcNxXIQGueExSsLaFsmMgCBbzUbBOoTUorLVoOYyvXnrSsAaMESfsvBKOZqQRwUxNRZzrnjnoOPpSsAIiaIsSizZfxAaXmMHVIiFShZsSzvkLdEFXyGgYiNnyYIugGUXPpvoOrYfFyRFSWwCTTLmSvOoqFfQvIiNMmnWmFAawqQWHqBbAawWZOoejniIrqQIAxSIisyIiYVKkCQgGcCqcSHOVNnRIiGgzsxXtkcfTeGoOcDhHvVdVSsFyYfUfFIzZvVIivWweYzhHZpaAPSYPpyleXxEqiIxxXllLFIiiwJNxXEUYyScCsoXxLlOSsBLIsWlRrGRcCrEeiBbkzpfRrFBAXgGesTtIPjJXbBtTxxXBGEumMBbUbSUBNnvVtuHhbBUOyYYSetWPgtTbkKkLMmLlyYlRwWrPpKnDNRaAEeMtOFRrfDFQqjlDGykRrCjJchCcXAaxiDdvqCcQVHhdKkKksiISDrRdJNfjJLUuucCQqqQcdCwzZURsvViIOoSjVGgvJPyYyQaAviIGgWwHhLHLRGgDEVyiIYmAQqwqhHtrAEjnOWmgwCylEFfEevRdGjJKnNfFkhHvVfFgFwHhHXfFCcnzZNYKkqGmMSLKklNnWwsJqQjUkCRrRIiFNbtTnNFfvIBrGYycHhooOmPSspffwCbIirRBciMQtTqXZzSHoORCbSsVvyYyYBqQvVNTtAiIyYaRriICDdkwmlbBLrqkZzxXiuGgUIKQzEeCMEeOoEeulzZgGJjJjfFkgtKSydTmXxlLdsSdxXFnAuUmDCPHhpgGfiIikfFKiIoJjwWalwbBYyWmpSssuxylCGbzdhHXxBinyYcWwpLlCBbciIViLWwlLWeEwpPCQRrjJwWTtCdcCGzSBSWtTjJzjJZonNOIitjJTWlVtTrWaAJjwRknuUiINeMjCHGtbUwQvSsalRgLHQmqQFCbffFFrbBNnMrQLlvQqVxVWXeExdaAlcBfFaYwWFZOoRGgMlBbriiKjTtJHvVhkzvVIOXhmvViIwNIinrRWMgGoRYyvzHdsShHpinAQyYmvzPpZveErRlpPiytSsTYLMmoiIOIyGgzZVbxGkKSVvMLlvbGCckKBFfrRuUOoTdDvVNrskLpPlUroFfORXeEQfmpPCNwJdDYywmMFoOHSpcfCcpLfFJtTLmMljRmMJJLvWDvUuTLIbBNSoOAaGQhoObBseEOZzypPrSsRYHyYYyhvVMYoOGpjJkgpDdvVoOcOoCPjTtJeNnAWWwwsIevVEiIhXKkSsgGxEDRrzZXWwLlxoORpYMRaBbuaAlLUAJjKkCcfdfFOWwfdHLeOnNoOqOobNAanfFSrpUCEwPpWhhHvSAbGgwWRgkwWfFAaVveETtZzSsfFKzZWyPpQqtTeEuKuUkOoUunGgrSthdDvVHXWwxPwWpHhyHhEeqdvcCVIUiFOoPWwhpPzwEeWNjILlzkKXxWtuUezmEeqQCqQcDdEnTbsCDduUcxXMYoeEotTOnlCcLDCcdDrcgOoRrGgGjCFfyYqTtkWwRrKYyQFfqtouUOYXxyeYlTtqQBbdBbiIJzZjJhHPrNQomMHiIhPVvZcpPAaCbByYQqFfYlLyGgzsSQqWwXdggNOqJblfUuSsIizkKBbjTCQByFmznNLyYVvlfNzNnQqZJjpPxXaxtTsStzWSRrPsuXBKkmqxEeytTJjCcYJmpPGgWyNnqQaIWwpAasSUuBpxXRmMAFzLlZlLeAaJKPpkHImMjPeEzZlLaAxXpAPnNptTapPJLluGgwWYyUxnoBbeRhHrKmLtTmEeZpPRKyXxQyNnYqjPpDrDMAamGgGfFgOzZoLlLlfFekKlsSxfFXjhHzEnwWBbVvAmMQqeECpPlLYzgGDFnNIiBGgabBiIAyYdPpBbVCcSYUMmhYyiLLlSqQTIhDdOoeEJjmXIipynNYPxnNwSylWeOoiQZzLlXKkbCcJjEeBpPhHMVbcMmCBxXvJYeEQFzZCDdpTNJsuUdjJNtbBfDdEehHGuUgyuhHlLUlsShHiILxLloOVGgMQHtPpeEekKbBEBblzWwwDdWroOFhBYmTPssSSiAaoOBmOwZrDLldCnNlLiIPdxtTXDIirZMYyRrmQVMsMmQEeEiNFpefjxXoFNngVrRHhxXbgIMXHhxAamNoZzycKkdDCFfAaqSwAnMmgNrpiyeEYIPAaxpDdzZRQqrTTttyBbaEeyRrYiIMmSsAIiOTRrQbBqGEeXvYyVmwWMWssKkGgSStTkZzlAnjnPeEAhiIaUuAlLdDbeZFNntTFfQtTNgGHCcsSZyHhiWDiIhHAaAaRqGghHVkKoVIkeEHhLlKpKFLlpipaMaPpoTtuUOZHhzTmGgKFCoOcwWNmGgvKsuUkAAaOFfcCofvDkKEleNnyXrEeCSscdDRoCNmMgOoGxcnNXxCPKkJbBlXrEeaAHjJWRthHTKiIKkATBbusSRrlLxXEdLbDdRjoOJLpfFguUOoaKnNqMaAQqahbBfFnNlLeaAxXOYjCcJHvUTbBOwkKJNcYxMmOyYiOoZzmiEGgeQqFGrRvVeyhHXxYrcnAUuVUWwuFnmMNtTfydqQDJUujKkrRIgSAoOJOoFdBbcQUHUuNSsOQZwWzawWAlJjHhWwPppPTtLGgwWDsLNncIDdijEdXasfQqIUnNbcwWEeCtbZlLzLlLlUxtnAaqQjJNwooSrRQsYqQUuUAarRxXucCZkKzyRfRrjkxZzXExuUrSUqJjTtYyXPptTZzVEybBYeeEveNnUDPJvvbBVtyYfFwZzhHpPWwTBkKHrRsfFuUIrRRWNgAUuaGhPgPpmOAaoGVUrRzWOowiIJjQNyLlpppqmMwWHvVhQVlLqXGZLwWaGeOzsSZoqQtTbBSBfIUembgGBIAaiTtJuUlZziILlAvljJtmclWqNnQGVEeXxvzZfFlLZzvpPdDCnZzCchCPpqXwzZWojZleaANnJKksFpMoIlLRPpDirRMmbmjJoOjoVvgGebBEYyoOjQqjJjIiZnNlLNliShHnsSSsqQGgSsrYyVmkKLlMTtzZvVsSiIHhoovVqQOOUDdHBBDdaAbbErRespfcCBhrRHKxXveEeEwWVJQgGqDdmTtDdMfFtTokKOsTCghcCtTyMJjmYnNcCDjJedDIGyYgriwNMVvSsLBMmbrVjofFMVNnvclLOoHhXxKedWlLiIwxTkKtXiISspmwWMPpCcxZzjJpzoqQOIyYXxUbeEYEhDdHjJzLlOobvVRrBKmNMmnfFuURMmrggGXxdDqFfUDKkYyeovVtQqHshAaKHTthkyQZzqWwYrYUUuMmwfFWuyYmTtwEQeEeWgGgpPGtTpPAawEqHFrGgRfTTDdmMVvtlgcCGKkLTylLIiNnkKjCvVcWwqjJmsSMWwmPsmvVXDdoOkjJjIihHJtsSAaTKoOkqQXRgGIXoLlemMEygGzBbZYMMQvViIouUuUldDjuMmUJPnNHhrCccEQQuUZzJJSWaAwsHhrsbkmoOMIixPCfFtTcXxxWwAaBVCBvnNiqwWfFTtXxQgBmMtTbTKtTBsagfFZzGwWBbYkKCjJOozZxXkVvLAagGyxXpmixKZzSqYCVvneEHCcYyOokiqQIYfEeFefAVviWJjwXTPptOEnlLNrROobBczZsSCtTeeeEoOhVvHDbBdQqTaxXDdICKlcCaActFWlLFInNHhUCcJjuXFsvVSSbBCsNMxFQkKtFcCZGgmXuURiIblHCQqoAsSnNaaAGgkBbKNcNnLpkKPuPgGqYyQIivnNVEqQebBCQOZzofFoCcZNEtTNnBXsSSpwWXgMOoWvVQqxXQyYqflSsLFfFfGgjJTtigGWwyYJZzGSsBbxXFIQqoOhgGnbQhQqqQHqBnfPDoAaOYcCuoOWwValxMdDdSZnNxIirMKzZKklLSsOolXlELFtgtAFewWfFxtTGYyXxioeTtZuUzjeooBHOoWXFqQCnNcBqcCQZoQiIlDGoEzAagUnoOEPMmdDsSJjzZpAGgBbmMuQNnqHfQqFQqjNBRrbtTTtLlJjKkXPoAasSXSnNAVHhvJjQpPwWfFEevVqaeoMmdtTbRDdHhCcGDdgeEDdrRXiHTkKtMmoOWwAaFfdFfDvaAsoMmzZWwIixXtsSTGeyYnNnNgGlLwWEUugSDuUDxhHXgGTCfFIUEGgiINHhncyGgwWkKYKkSxwpiIZCMmPpAaZzWhHhBZUuhdGaAguZzHhUGgDBbZbvOosSAlKzZZfELqQuUqkeENncifFRSsqQMmrlLYRmMtTDdolLeEYtThBbJjHGSiZqQEKPpdDkIKxpxwqhHQhYyWwIiGkxmMpPjJXNnKKkkqQKCcgKkCuoLvTeEnNCcNLlPpYygGwnUvXxVHjYyQqAaNMmDHzMmZMmhISsZzTbBEezZtXeEHhxvxoOXqtTQMdwWDGgrRqQUuVvjRfWwYeKkElLyYCcnNueEMyPmuYyUpczVvzxdDXZwWogGgGSbBXuUuUgLlHhmMKktNhwWHIXxjJiItTBpIVDptTmyYlLSsMCcDiHDdhIfSsUuvVCcsfFSzArRaZHjJmMhkKFhHPpyTtYdCpOoiaGgAfFZCcQGOoCczqQWwZsxFfOoeEqYFgNnfxdAaDdfmMBKkVvbFkIirRGgDdsVjJvcLJSsgGYjuUasSZyYEMmMJlLjGgSVvNnstzZTyYmrRlTtAjsSlLdmsSMTtDZzJSscCpJjtkKTYAayPdSTKlLfFsRhunNUHdPpGgDfOoswyYWTFfTtCTtcIyZzYipmMnNUqfFCAaDdzQqyYXBbNnxeERUuCcrUeEPpxdDXgGSAapPSuAaUsDdsWwYyuZHOqVbBrREerKkdDeEPphxXHBbCcYyvVqQOKbBkJjoupbBPPxCvLyYliIVECcepXxPsSWbBMcCcOopIiWXiIxwLlpxXPQxRDdvjMfFriIDdeECcjCrOoQOxzZXZzoVZznNoOvsSPpLlmukKUaARrXwCodcyYCWAacCYRrMmZzmkdDasSAcYydDCuUKNnkKDpPhHdMmgGACcaMiImwYDdUdTtDPfFAaLVvgGlqQqZzTtwuUNnNyTUuOCMgGmCzFGoWweEOyYojJOUumDfsScGwWWwjJmbbBfWlLZkKzbBlVvLVvrVvRlTvmcasXxSUzZzqIjJiUPQLdMMdDmRrRJhHhHBbXOUuohvVHJjSjJytLlTxskKYybIfrRmMFiPDrbBCrVvVvRKkcMvVOzLlqQZomRXnRrvVVvlwxXWFfKgGTmvxXVkKQDMlLqQqqQUuczzuxhHXlLZSsmxmaETeqzZQESaNnAcCKkRrlJjrIAailXxLwuESlLhHTtHDBqumMUceECBuZOozUsSvRrHRPKwkKnNKWwSPpOoPpuxIUuGZqQzDdpPQqrpPRJDuLlCAauUcLlXFSAaUJjZzCswWBzZcCbWwVvSBYGIOyYvVoYyxXAwWtTfPpMfFEIWwiUuotnNBbHhTtGgTpPdDODdGgbVQIPeUuiITtEpiIBbfqHhQFHhNnslLSWwLjKkyHCrEecqDnNyXxYYyoCceEqYDyayIisSoOtDBbwWoOSpPYmAaGeEgMiFfcCRrITXCcxvVSbORFJXtTxIieEjYOTcCILliugGalpCcPJqLAaYZzpYFwWeEtiIoJVvjmMoOzZoOoBbLoOyyYYdDldAaYyRrZUuqxXGgFBvnNBSsDsHEeLPplzQXxdDqpJkSsKzSwtTWWwFfsYyoxXsOsXxdiIDxXzZWrgiIGQPpyYqQqQCJjcQqBQqbqNnUumMJjtsjJzZkKYyOoSOotTvVDEfFEeLoOVvJieAavzZfHhSssSOppPPGgLlSuUilFffFZpPsQqSxHhNnXMyCcEeYanNAlLEdaGgGoONnFfgGliIAnNaLyYDIiAaIyYQUuEbBeGTPptdDrwWRHhXxxTtXubThTwWcCBKkbgGNntgGPpVvzQqiIDdPiIpTtSQqIiWwOxSsXogoOGofFIiOzyCfpMbBmUiuaAVVGgVaAxxXXhHhjAtTTAaTWcCwyjbBJNDdQqenCcPpyCXWAamMAUoOfQcCwWqFuvwlyYkKLWVRrdfFhHBbviIgELlLlTtSbqGxXgQBnWwohwYyOowhHWsSvVsSahDdOoDwGgrMxXmLlSsReEQqSsJGgQoOeQqOtToGSsgnNyHhkuesJNvyYzpIiPmdDMswdDwrtMmTRVvxxaZzPpfBZTtEehBbHKkzAmuUMFfFuUvVfahcCEeHPPppYymMbGGgNXyYyLlSVvuQqUsoOBbUugDdIymMAIiaAPpaBUubTPVWTNnkOsvVSbBMmseESJQqtTCcjkaANXFzZfKkeExKktTXxXaAItTsSxXVMVzZmyZzYrRxAhHgGqhHrRdRKjJkrzLlWEodWyYEvVWlLaEedFfQyLlYqDAORroMMNkKnwKkCcyHNTtnhLkKdDvMmKTJCcjQVvmtTlMHIihvZUubByYAwWWwaeUuNxiYyeyTDdtuwWshQJjqfFnSsHhPYynRjVvQKkUuyLtTlrCiaQrFekLlctTFuHSsgGvyYkKVPpjwWBlWEQqBbseELiVAaiIDdYdRoOrzZDmMkKzuUsSSsoOUukLOqQvVPXxkgGKJjlOoLACFcCTKXxktQSsTXxtqjJVFfCcmLWwnudZzDUNdmOoMScVvCEesDTtemMAawWpPNnrRaQqACIiAaJqQjqwfFiIvgGyvvVwbBNgmnKSsgpPpPmbgGBKsTJhchDdHuNoOsSnUqTphHRrQrRqvTZztWwRjJZzPpruUGxShHldDLGgsTmcoOxXwIKkibBsLlnJjNMsFZpPsZzDlLdfWwdoODhHFnNfkXxKKpPtGeWwjeEtTEeiIRrkKJEUCOSIeEWwiEeFgGKkFuNFfKLlMmXkOoWhHExXhHpPfVvWwFelGkGgUuIixehHKmMOWweifFIDUudEwTLAaBbcCNnlDdaASfFUWwuoOsYKkyrkwAaRrbqMxXoOJBbjmElLevpjJaAvVXNnosSOqNuUnQsgUAMmsxXQOoKetUuTZpPZxXzfYesrRLpPJjlSEmMejqQJjJDdjMTAajuQvVqwWmTtyYMsSgcCGUyYuEergYaxXFflAGgasSKgGkQqdDLGglQlLqtlJfFfdYyDJcCWwjTdDtFjOySsCEqtTQeFfeEKkDvVftnNtAuxXUaTJjQAuUoOTFjJdLlNDdnDRrZzImMsSZnTPptsTEebaLldDQqoFmErBbQqbfvVFBZsStiKkKqshkKEegkKrCmZzMcTrRtQqmKDdcFfCaAzZkKbrySsEKkeXPpPqTxXYydmvADdJjFfJjYyctiIVNnBbNnMmhHnmMKdDkMjnUBbuNJRrJjmNnNvPpOoOoVRrEVsStBbXRabiIfVvFhZNnzxRyYJjVvGgNnOpRtTnZVvoFfUDduoOkDyYDddKVkBqQJJjjzZOFfpPobDdGsPpjLlkKUuJhHfFBJjMmbXBbXxGgzZpVvVMTAXxahNNnnfYyFvvzoAatQqTOqRrjQVvfFbBsSHhTyVvYmQqLlMlLpILlXuUsSDmoYWHhOOMmooiDdIwWkwWwWKGghHwhvVkKvtTVbBZZzTtMNnOMmbhWwHNneEtTsSQqcTtSKksVTtTdDwWRdDrlLzZtxzmUwWIiuKPNnpENzZHZzxnhkXxZGgUuuKVbBmMjJRtxXQSsqkJjkqQKeEsqQBbBhHHhHqNmMqoOpCpPcPAMmyYsZsKbBkSpmGgMpTifFvpPYyVpPIXPpxuljdDJTBMmeEdWtYyTLvTxXtVHTtvVOMmoEozIiOorRgnNKWgRrOorRZzkqQeIuUdbfFfJjFveEZvqQzZVdqQDqLnvRLloOrVKkvOzZpvVMYpPuUyseEPpvVSeJzgzmKnTyYtFfAabrRLpPrRalQqLVGnvVNgvMtTkKWRPpxXWwMORXdwWeWwJjlbBSsyoHkKgNnoOnNDdHhGFfWwKklLhOezZxXZgKkGEAawWezETLJnNPpjAalLuJjQqXxUMCBbtHhrRrRyQqQJjuUnNZQaAGCCcYsETkKtpPwWSbFfsEoOeZKkOozQYyJjVRryMPplLAaSspzNnZPqQWPpBbGgworROHzNiIxXSGCcgXIiCgIiGlLTgiccCkKlHJBulxUuLdDdDfFVvcCGgEkSsdszZSmMsSuURQqrDJjBKkVvFBpPjhHcSwWsMTWwtBAzAfFaHRrhtYyhOxXtQuUkgGYysSkGJjgqQMoKnIhHdRrpPDCLlMAaBaARrTtyYbmPsScyYTtyYWCkDdLlgGAhHanNFqOoTIitQKkmlFfzQZRrcCuUVFgaAGfveUueUTXGNSsngvvVdDiJRrlLenNMmEeERIiNWwUmMuEeSLdDfFlqQLFjJfBLfTYGmMgycCtpPUuMmsESlLsxXeSfUuFICgGEzYRrbiIBHCeDdsAaWwSxXlLlLsIGginNSUqQDWwYBblNHcjJcCoOsxXSuUTCctMmHURrIImMRCcrNnAaiJdDjAawWUuRrxgGXKmMkAaATtahHFfBbiIfXxXAaxQqQqcCoKeEHVvhexXzjhBbHCcAaTEeADddDadDuWwPpOIifUOxeEXDTZLAeGgVhHLavVJjtTlLADdlBdSsUeiIMmfJBbjQqNecCEVeEvBbcCCbBtTtTchXxTvVIOoEeiugGFfQqUYkKLebBDdEeesSvAazZFfzZTOotpEepPpPcVxLlQQpPZqQJnNRrjlLzDdhHVvFlQqQqQQgGafRrLuWyYwEejloOEelLeECDBbtTdvVqOohyYjWweEJsSBbTTtAYyViVvIyYYyRrOoLlvDnOoWpIizcCZPUaUQbiIByYquZzyHhYWXgaAZNVvnrRNPrRULltTQqpPxXUxXXxsnNbepPEsSKWtFffFVvTgqQFfGRYhHHwWhyYyrxXzCEecSlCcFJgGgGjKtEesWwubBDdUSNnKyYuFgDofFORrdGUuLYUDdHDdtTHhBaAhHcCaWwLlaAhEezLrRlOUOoHvVxXfKkLloCXxNnTtnNkKnYEsSeBbgOoLlHCGPpnwWzJvXFfxrEeJjxXcJIijCcQqMmGgCEhmgGKkSsMBbwWRjZztTrRJkKlLGgZGgzLbBlxXcCawcCxXKkJjtrRyYuUEtTeQqTzZzbcCBZAEWDLlEedwFFuUfQqrZlFfpoKkOFfqQqUBPpbTtIikiqQqQHGgHQtWwTrRVyYvyYqSsSsVFfvEhEeHkiIKeYyEVMGuUgmTtorRJcCEiIOaZzOoKkCfFbBGCjJcKkgSskKzZDdTtcHhnNuCqQcuUoOIipuUtTtTLlmkKMAPTBbNnZzTcECcjJtTexXEQqabBAXejtTJIiDhHwhHeEsBxLlVvXOHeEsQbBCcqiIOVvodxXaAuUDdAwLlWFfouZSsDBbdeElLvsbBVvKkrbBSEelkPpKLIcCeEKkZuUzLlEZzesPpSqDdIiaAQiFqHgGwPpCjJJOojcAaZsSmMzKeEkDFrRBbyGlpPLWiIwulLijVaACcQNnqNntUuqQRroOtbBMmwRTtKkrCFfclQqLJjWCcKcCNNnBbaAjaAJndDYyWnNwHhjJIiQKIBbihIspPvVpmMPfiInNmDpPpPdlLHjJyvUFaFfAsSfOoxXJjyYwWvhHUMmusPpSfFVQqtwRVwWvrLyYlSKkTtZzYrlRrvVeEKVvkAaDdRrkQqKqQaNnpWwyYPAaGgfkxXyYKFlLaUuKkucPAAQqaqfFGgeEQBLLliIvXafFAnNpPoOpzWwZVvHaAhEuUeqQPaFxXXxJuUKZOozzZNnkfFbBbnNBXxCmPuIhHiVszZSEfFCxTtXBbTtfXxuvnNVUxXuofFOyeEvVACceCcEvVUudMOoAamtuJjUqmMwWQTRrAaAaoOWwpPkKtcClLVrUuhHRxcCKOobBkToXqhMmHLgGvVGrCnzZNcvyYrRHhGCmMcgjJioOIlLrxXWTtXRrhHxhJjVvEoOiIKFfYdDNnQqywaAoZzOWjJiTkcCKvVIitdDXzbBZIiuftTFNnGgJsSmwAaurFfKktTvfjtTJoOFcaACVgsgeEGSLldRrBYJjylLQqbDoObBvRrtTwAaWwWRrDdaeyYpPmMlLSsriIGMmKktKkKkMyYWqQiIYykaAHMmsSGgLlCHKaAIkKcAaCikZzaAOoeNngTtSsUuBDDdpPWwaxXpPwEeWNTtneENnNPOooOpnVfmJNnyYjMFJjwWeGgGgELldiImMDMmeETtsSjlLkYybxyYJjXfFdzHTjQqaCmMIYytTibBpPAyYgGUNGgnBbjJurReJFfjETtvVzZVbSsBoMmJsSjOwWaAUogHhjJGpPhHnvQqntTDdEebBNHhaALgmMlLoOgVvGKfFkGOPjJKkpbZzmMnrRNyrtTyLlYEeEHhQqFZExXXMRrOoknNsSnAaIiZbJbjJBaKkuTtUNnRAaLExXwWirRQqIeEetlaAeiICxXnNvMAlLagZzEelJLlXzkKZtKkFfyYTfVvFeMtTBZQqzKkXxOoBGgbjJHhHOohKkVIivIyfFYjKvGgLlVRrvkQqKhXvVAacwWCSszhHZGgHnNhujJsSnNbBbBKkvVnNiIromOoMdDsFfSZHhJHRrGghaAxXWgGJmMmHhhHXKsSLlOZJLAalnNzZSsOojQqgVrRWNqQnmMwJjbBjSKRruUDdkksSKWSswYGgUUuuyxXCfFcmMZzOGgDQOowWZUuzjJJBbjyYqQTtjJhHSJjYysDxtTXdDFfItTiNbBAOofFaBbVVvAYMmNnyaaAlEeHhLUbBNnmMBYyRrRrKxBbdAaDRrXSsJjzZkAPpxHhXnoONaUXwWxYlLmMRYjJxXfrSpJSsjJjPphHPnNiIHhsqQFfiIvVBbRcCQldkKDLRrpPWfFwxXrRXxJrIiHPpSshRHKAasoVTtLlcCmGgmMMrDdRvdDOSgoORtTbBomMLMKoORbBnbUuUuBXYrRyrRZqEDdQqTOoRrtlnNGtTZzeEgeRcCJjkKanNGgAzZRrSuUtTrRzxXGaZzMmAgSlVvJjKvVklLbBThMmmMnxXdDlqQDdLBOogGgGKkpwVvxXRvVroWFSsEMDlXxLfezZmMxXEsSFKkfFdmeEeEvVesSrRCkKlLTtrRrRcfgGeLMmpWQqdCcDwRkKrpNHhmMnNnUujJbBNaACEebBIfFisSBYwLlaAUEezqcCgWwGQsvVcJfAYyaYyVbBdDJzZlLeFfmQqMUuIyJyYhszZSHaAjiIEenixRrXmMINxtTXzQqZDdWwsSfzZGJwLEeZzjSsKkJlWjPSVvspPlpPoOLRrXRrcCZzKLhHlhGgWwVvuUQDdTtTtqiIHJjcjJYQqOoyPpAJEenXERzZZzTtRRrmMoTtjJSNnsvViIZzHhyYxXeEKPoOhHpkuUueETtlLUAtrRrRBbTPuUphHIejkKmMJtTEPHyYVvGgWwbBkKnNlWwUuLYynKpTHhGgXxZzNntvVGdDgNkKnlLJjFZfFzHhfYyREerOoPVvoeEPpuUOPAnNMmLuUlwXxsSHnmMorRlLODNnGgQLFfFfliTEetVBrRbBVxXvKefnNiKvvVzTthHEeOoZYyiIZzVaATDkKdMmWzhHZwtaAOuUoWwLlklLVvbBHjJimnnNQqNMIiIqoOHhBRreEzFfUuZDxXdooOOrSsRbaYEeyBCFfcbfvVFwLDTtdNfKZJjhHYyBGgbCcTYhNhHPXxwWpncCyYoJnNIijOffsSFFOaAKXxLlkogGenBYyAffFFEeaguUzZGQqbrRPpSOsgiWwIhSrkKRSssfzZFIaRNnkxXEepPvBVvUzZunNxXwWzRriIIpPrRiWTtuiRrIqyYaAcRjJrCcCQlLXgiILlGIidqMNnFfuUmQgGKkZzKkDTtASUiIuxXeLlESeEsHhtTguUGIirRnNSsfFPpGghHioIDdiWCclfFLorRNSslLqQVFrbmMBVvaBvVHjJqQNwbBwDdWGBbgpCRrHhqQrXOfFoiIxgGGALlagSxXXxseepnNQygGRwWrYnNOoUuMmquUAabbBBCzjJZcNEaAoOenhOdDUGpPKnqQNVnAhHaNvCcBHhzZbkBbcCvERrZzyeyYIiEYNnewWnNSJEejjvXxVJjJVDdBbbBBbvbBFfiILlrXxoxleEwWRrLGgKkAaelLQqECcuUXgCMvTTviIVlQqWwtTGgSsCcKkqoiIOQTtAaPaAuUEepHfFhXxbBLKkJjSzZqQdDbtcEelZIimMzIiAaPpRrcTtFxpPXSsWwrgoSsOwWuAarkKRzZFfDdvyYpPYyVUGAaRQAagGkbOolLBKkWwnBbMmbBSssWEeOAaomXOoxCcyYtTyYRmRrMrMwWHhKtTkwvvdhHuUDmMxXOnNooGgOVVFfwWOoYyrmMdDRLltynNlLwWKkUuAaVCcvjJVWfeEAaSUusvVFYYoAwaqQARfIiooOxXKEekOFuUAdDRMmraKkYzZypPYyFuUFjJxwlJjLZzWnNXcCxcCnSsNawWAzYyZXSsIoOAaRruXxsfFwWDdSfoOFUjJLzZiEeTJjttTIvVBbqQcCRJjroxXHaOoALleUuEhOUNnMvVqFYyfQmEnNCcXxwAaWkKZKlIiIlLaAmMCcJGgFfFMmfGgyYWwuUIilLeHhMmPIisSiIhHzZqkAaKQaAvVjNnncCtTNJRrcCYsSWwyuWnaAoOTtNxXwPNnplLYHlLCJjjfHhFiIJYdxXPpibBxXIZMFnNfmzDrRoUuOyoOHhMfFFfIiMmmCzZameEZzMmjJMuUrRIiaAAoOFgnNBRrUYeEBbyepPcCEubBnwWuUEeuOoMFTtfmrGgsCcSRFfARranoONmhmrRMfFNnHvVxXEeVnfFLlHhNeERrvqgGMRrmQaAaGgAHUudYyAmMrRaZzvhHVYyWCcCcoOVbBvFQqfwcjJCDHhuUtTMRrdDmtrHhvVRBkKbyYbBqQqQDDdaATtIiAbvVIWaAfFFfwjJXxXxiuUNrEeRJZzzZjoOSsqQFlLfjJpPnNJNoAaOnpPtLlAtTvVKVvkazXxZMmwWlLhlLIiMmBbsDpPAafdDEAMOomZzaJjRryYehHvOgGzDdXxAaZgGpqEeQPoEhHefFLlSsjNCcfFJjoOuUnUnNajJAaAymMYIilsSyYTtLCkrRKcSHhShHvVDgYBoXAMdDDdmcCCnhPpGgHNcahTtAatuUuVToOtvHhUyNnmDdaAPpjLkKlRrEeJJjmxkcCpPKtTETthxXtTHhnNmMHGiICciNdkKdDDWwnTbyYNHhfFuMNnmpPzmTtMZpcCLOolEOuUCcouUeWwPUnkKdLlcCHiIhBbFfDWvVnNwOcoDdOCoLjJsSQUuCNrTyYiItzZwWQqPvVEeJLKklLlFRrfFfjJmMaAIizZsUQqRSsrpPSIisLCcuDdiIcEeJjCUuKzYXGgtQNnqPpXZzqMmLlgGUdpPOoSsOoDunNnNZPxwWXpzFfIKkrRaAxXgWvVwUudDaAfFbBZztTlXuUxFfLWRcCrEVveIiwyvVkKMmhHkKYbBNnnqQNqQeElLwQqhHOlLoWhHbIioOBRrwWewWtotxXtTTfzZFOsSTDzZNndDdDdkKXxjTtJJjAyYCcaXxtKkTQXIdvVdZuUzSsDAayIiYYysSnyYRTpPfFtrkKNrJOoyYRgGMmuUrnXxlLSsNPyYUqLxXlQaAPpMOomuILlipzXZzxlLVvFfzZMeEJjwlQRrqlUTtUufFuVkAaaAEHhenNWkeEKsSTtpPrRgGwATyNeEnRxXrAbKkBaCcqQYmMBbHCUuENnemLlTGfFgtOsmdDAadDVvqQvVVmMvGUuguUpHGghZiIzhHqQPSsTJjGzZgsSGLlPNnqQKKkkCcEeJjpuToOTttUOovVoUuAaTLlYyeEtjAaJfpPxXFAamMhFQqKmMkBbCFfcJsSTqaMmAQtjIioVvOMmfFfyhqQOoHeNnEYzZJjXTjhHJEetxPphHtTTtXJoOjGGggWwuNnUuQqnUuNYyLqdDqTAEeuUakKWMVvZzmwjRMiImrJjxXLllfBKkbeEflKkLVvmKkcCYyZzWwnNjJMFilLbhHlLDhHGWDjJjVvJdSLOolGgyYsGgUBbMIiKXxkYyYmMyBqQGgbcCmsDelDdLYrhHsSReEKkPppPFfMmzZdeEzVvqQcCOoSsUxZzeEEKkQqYyxXeXujJFLbBxXlzZqiCmMNncpFfPIRtTrjJWlLvetTEnCcNRrVeElZzLwqQYypPvVFfpPtThLlMmNaSsAnyYLlHXxjJXVCZzcgGvxPpLQOoqdDjEFirRIfHhBbuUeqQJvVSaAaAslFfjHhJrPpJwqQBbWjoORtIiTiITWIiwtLlcCEedDjUuWIiSRrGvVjJgsBwFfWaAbJsScCRrjfFrRGHhxPvUukIwWiKVpAavYysSPdDeEPpKkpreEGrlDdLRtzZqQnNTLyYYystdDTSOGgojJmMEwWelAACcaagRvVdwWeEqQDFfsSdDIirWUuKKkoOBbKkzZkKkmMxFyYfhHGXxgXGgRcDdRgHZzmMVvpKkPhxXHhGrCIifhHbQBbrRKkqrAaRbiIUTMmFftaAIiuBcCjaArRrsJgsSGPpjSkKdcCjJDfeEWwFmMdwWkWwkKRrKDaOkKojJASsqQXxdDqQEerRszZSRqQOoMKzZkzZpPBmMbmpPbfPgGpsSxXiIIiGWLlkokQqKOKoONeEnuBbjJUuUNnrlLBcCycClLmMYZzRrbsSYCcJLlrRjyBbTReEBbxXzaAZaAnNQqnNGgrgCcGtLeElrgGwJUujWRYlLtMmTyEhHefJjNnEeLlahcCHACKkcaAOorRFvVmMFrmIiLlMeEzZrRRgfFBbuUSsGRrnNfYykPpomMOIiKYbBhHzZyMmUUIiOPpHhorRupPdDuzZLlippPPxXTtLGghHlyYZziJjJjIlLqQZMJjaAmxVvrRtTGgUupPVHhAaUuiPoFNnxXfZzPcCpPPprRZzzZxXpMxXmmQEOoWwmhHEeUunUlLuRrNPHhpRrMBbiiIIeOKkoCbBcUEeugGQAaqwCcoMmzZwWGgPpjOEeoBbKkrRDdOoZzJxRnGgNqEegGtTXxjJstTOoSvVgvVAaGSsCcESslLOoEeNMIimNntTmMxXyYiInVveTkOoqHhQbBKEeCTgGtHyYJjNnzIiZhcVsSfFYykKOHhoRreEjsSXAasSYyJdDrIiRWwVCcvhVvizZrRkKAaeEhHWwBUTtuiaBnNbSsAMHlLmMrpPRhmGgwMmWIvVbIRkKDaAYydriSsaAsiIsSxXSHhYyrRuNnmMUdDrRzysSdDkBbKqSsQYXxOfFvVGgxOoqeEQXZVvOyYozjpeECczPpzZaAtTkKfFvVeEYyQqcCGgcCyMmYiIZPCcEZzyYoOoOotTYyQtTqOLllLstgrRPpaEwoOWbBeVvAGTMmJjYyIGgyfFYfeEFrrRfFRBbQqrRhmMxJjLCclxQqcCXXxrRZDdzheEHYyXTaXxAhrRHuUhEeFfHkKVveExXxXwWTthWwWwUuHtQqWdDwNnfMmFNnobBJjmIhHkKXxxXXxiBbpPIDMmcCpPvVsSgGdgGhSsIDdkYyKiEenNcCoOGIicCDdgHZCcDdKVvkzHhkDLldKWYywSJjsSxXYyyYUuVvJiIWwKSIiskkKHsSDdObBBbBboNDdnzZBbgGOKZzkHhVvodBZoFfOzbdYIiyBbmMmhHMGgCcdDbBetTpPEEeWlLJjyYwiIJjqQKzZxPpXkRDdBbrdDyYKTHYyhGgGgthHqQtTsSkqQqeEiIBbXxQKGgzZVvgGkKXxkeEoOAaNKfajJAsSFkZzIyYisArRUuaTtmMSUuenNRaOoAWwfFrTaAtMZxXzmEHhCiIpPWwLlrRcVvxXRSsdDraAvVGWwrRgTtnayYvWwVeEAEepPBbfeEFUujJwaAWOiIoMsSZzwWKVviIkIimauUNiYyInAvxXgGVvigqQGIHxXhVaAvwWFfVaAiIrRCzZcrUuOuUHwWhopPLPNnSspHhVvgGlcGgNnHhPpzZCiIhgYyyYyYSsGeEBfFiIbkKMmEevQqZzlLTnNttTVHRSsAaRrzZQqWcCwgkKTtaAltTLdDUuGbEeBbkKMjJjmRrMjJaAfFBbBbJxXFfAnNaIiIaAvViVkLlKiIvwWwWFfaeEGgxPpXiKkIAEeagGABbuUYyrRQQquUEpPeWwIigGqRrZzAOogSsGaPpnNFZzUumMfFrRIizZfXmMxXxeWwTuUhNmMnmHhMHSssoOStPoOpepPNnEZzEtTjgGzWfFwZvVfFxXJTtMmMHhtTeEmhtTBbHhcVvCmMmMUuKkxdzZDXPpePpYyEGfNnlIiLFfJjKkpoONfmMFLlnEexRruUPpXUuCcPFfJjzCciIZRravVApZzaRrRnNrAVvwWbBZzWwtTkKNnyYlrBbRqicCFfAaURruIHhTgFfGtKEeKkCDnNdcxXiIkQnNFfkKrRpPqQXxTtDVvVmMvKLlIgYyGikDtThHduPpUGgnNbBCEDkKSKnNksdZzeCcEaAyYubBQqSsHhXpPyYxFfUeIiEHdSlLswWDCMpFDdfPmchMNnpPuUmtTMmEgGgGeRRcmMCHhrWwYyrZzDdrRPeEiIlLnwWMmNEepTjJfFDlLdMmATgGJjJlLjlLthZsLlRSsrWwSCczHfFzZfFjJalLMBbsAaSxXBbowWdDczyYZzpPZyYtZzTCyYvbBVOmmEeSsMaAiIFoOpoOHhhHKHhHhXxXxkuEbUutTBejJzZLlvKkVxXNHhnsvVXxSrRqQFiIftVvTqRrQoaAOMmmMfFUvVNnuurRUEJrRjuUhHuUZzgGTGgtKkEedDrRLIilpPeMmMTKktnNHhKkPpmlLjJZGgzlLJUkKuwWUgGuvVcCUZzuLKklAaKkqpstTScCCcPoZzOJjvvVEqQepPxwWXdDVvXxQxXadDoOkKAqMmEqQwWeVQoOYeEsSyeEyYjuhHvVXxUzZEeDdCcoOOoAVbBvcCmMaLRrlyYlLRruUGwWtsSkUuKwWTFfgsSTZzFfVyxXYTtvcuUCtuUGgRzgGZcCZzvVEeUurVvZzHheEhHUUojJOuuMmUXxgAvVDdapgGPgGKkiIqQGrRufFfFWwFfSsDqXxbiIBQGgCcyYnNbBdu";
+        let input = read_input(5).unwrap();
+        let input = input.trim();
         let reduced = super::polymer_reduction(input);
         assert_eq!(10496, reduced);
         let speculative_reduced = super::polymer_reduction_speculative(input);
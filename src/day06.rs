@@ -1,44 +1,101 @@
+use std::cmp::Reverse;
 use std::collections::HashMap;
 use std::str::FromStr;
 
 use regex::Regex;
 
+use crate::day::Day;
+use crate::error::AdventError;
+use crate::geometry;
+pub use crate::geometry::{Metric, Owner, Point, Point3};
+
 #[derive(Debug)]
-struct Grid {
-    tiles: Vec<Option<Point>>,
+pub struct Grid {
+    tiles: geometry::Grid<Owner>,
     specials: Vec<Point>,
     length: usize,
     breadth: usize,
+    metric: Metric,
+    origin: Point,
 }
 
 impl Grid {
-    #[allow(dead_code)]
-    fn new(input: &str) -> Grid {
-        let specials: Vec<Point> = input.lines().map(|l| Point::parse(l)).collect();
+    pub fn new(input: &str) -> Result<Grid, AdventError> {
+        Grid::with_metric(input, Metric::default())
+    }
+
+    // both the nearest special point and the safe area are measured with this metric
+    pub fn with_metric(input: &str, metric: Metric) -> Result<Grid, AdventError> {
+        let specials: Vec<Point> = input
+            .lines()
+            .map(Point::from_str)
+            .collect::<Result<_, _>>()?;
+        if specials.is_empty() {
+            return Err(AdventError::Parse(input.to_string()));
+        }
+        Ok(Grid::from_specials(specials, metric))
+    }
 
-        let length = specials.iter().map(|point| point.x).max().unwrap() + 1;
-        let breadth = specials.iter().map(|point| point.y).max().unwrap() + 1;
+    // the same grid as if the excluded special had never been there, with the bounds
+    // and every tile worked out again. None if it is the only special, since like new()
+    // a grid needs at least one
+    pub fn without(&self, excluded: Point) -> Option<Grid> {
+        let specials: Vec<Point> = self
+            .specials
+            .iter()
+            .filter(|special| **special != excluded)
+            .cloned()
+            .collect();
+        if specials.is_empty() {
+            return None;
+        }
+        Some(Grid::from_specials(specials, self.metric))
+    }
+
+    // specials can't be empty
+    fn from_specials(specials: Vec<Point>, metric: Metric) -> Grid {
+        // the grid only spans the bounding box of the specials, starting at origin
+        let min_x = specials.iter().map(|point| point.x).min().unwrap();
+        let min_y = specials.iter().map(|point| point.y).min().unwrap();
+        let max_x = specials.iter().map(|point| point.x).max().unwrap();
+        let max_y = specials.iter().map(|point| point.y).max().unwrap();
+        let length = (max_x - min_x + 1) as usize;
+        let breadth = (max_y - min_y + 1) as usize;
 
-        let tiles: Vec<Option<Point>> = vec![None; length * breadth];
+        let tiles: geometry::Grid<Owner> = geometry::Grid::new(length, breadth, Owner::None);
         let mut grid = Grid {
             tiles,
             specials,
             length,
             breadth,
+            metric,
+            origin: Point::new(min_x, min_y),
         };
         for i in 0..length {
             for j in 0..breadth {
-                let p = Point::new(i, j);
-                let nearest = p.nearest_point(&grid.specials);
+                let p = grid.point_at(i, j);
+                let nearest = p.nearest_point(&grid.specials, metric);
                 grid.set_tile(nearest, i, j);
             }
         }
-
         grid
     }
 
-    #[allow(dead_code)]
-    fn largest_internal_area(&self) -> usize {
+    // 0 if every region reaches the edge of the grid, and so goes on forever
+    pub fn largest_internal_area(&self) -> usize {
+        self.finite_areas().values().max().cloned().unwrap_or(0)
+    }
+
+    // the special point owning the largest finite region, and that region's size
+    // None if every region is infinite, ties go to the smallest x and then y
+    pub fn largest_internal_area_point(&self) -> Option<(Point, usize)> {
+        self.finite_areas()
+            .into_iter()
+            .max_by_key(|(point, area)| (*area, Reverse((point.x, point.y))))
+    }
+
+    // the size of every region that doesn't reach the edge of the grid, by its special
+    pub fn finite_areas(&self) -> HashMap<Point, usize> {
         let mut result: HashMap<Point, usize> = HashMap::new();
         self.specials.iter().for_each(|point| {
             result.insert(*point, 0);
@@ -46,42 +103,148 @@ impl Grid {
 
         for i in 0..self.length {
             for j in 0..self.breadth {
-                let tile = self.get_tile(i, j);
-                if tile.is_none() {
-                    continue;
-                }
-                let nearest = tile.unwrap();
+                let nearest = match self.get_tile(i, j) {
+                    Owner::Single(point) => point,
+                    _ => continue,
+                };
                 if self.is_edge(i, j) {
                     result.remove(&nearest);
                     continue;
                 }
                 result.entry(nearest)
-                    .and_modify(|times| *times = *times + 1);
+                    .and_modify(|times| *times += 1);
             }
         }
-        *result.values().max().unwrap()
+        result
     }
 
-    #[allow(dead_code)]
-    fn largest_safe_area(&self, limit: usize) -> usize {
-        let mut result = 0;
+    // the smallest and largest coordinates of the specials, the corners of the grid
+    pub fn bounds(&self) -> (Point, Point) {
+        (
+            self.origin,
+            self.point_at(self.length - 1, self.breadth - 1),
+        )
+    }
+
+    // every tile in the grid along with the special that owns it
+    pub fn tiles(&self) -> impl Iterator<Item = (Point, Owner)> + '_ {
+        (0..self.breadth).flat_map(move |j| {
+            (0..self.length).map(move |i| (self.point_at(i, j), self.get_tile(i, j)))
+        })
+    }
+
+    // One row per line, like the diagram in the puzzle: each special is drawn as a
+    // capital letter and the tiles it owns in lowercase. Contested tiles are '.',
+    // and specials past the 26th are drawn as '?'.
+    pub fn render(&self) -> String {
+        let letter = |point: &Point| {
+            self.specials
+                .iter()
+                .position(|special| special == point)
+                .filter(|index| *index < 26)
+                .map(|index| (b'a' + index as u8) as char)
+                .unwrap_or('?')
+        };
+        let mut result = String::with_capacity((self.length + 1) * self.breadth);
+        for j in 0..self.breadth {
+            for i in 0..self.length {
+                let c = match self.get_tile(i, j) {
+                    Owner::Single(owner) if owner == self.point_at(i, j) => {
+                        letter(&owner).to_ascii_uppercase()
+                    }
+                    Owner::Single(owner) => letter(&owner),
+                    Owner::Tied | Owner::None => '.',
+                };
+                result.push(c);
+            }
+            result.push('\n');
+        }
+        result
+    }
+
+    // the special nearest to any point, which doesn't have to be inside the grid
+    pub fn nearest_special(&self, p: Point) -> Owner {
+        p.nearest_point(&self.specials, self.metric)
+    }
+
+    pub fn total_distance_from(&self, p: Point) -> usize {
+        p.total_distance(&self.specials, self.metric)
+    }
+
+    // tiles that are equally close to two or more specials
+    pub fn contested_tiles(&self) -> usize {
+        self.tiles
+            .iter()
+            .filter(|tile| **tile == Owner::Tied)
+            .count()
+    }
+
+    pub fn largest_safe_area(&self, limit: usize) -> usize {
+        self.safe_region(limit).len()
+    }
+
+    // every tile whose total distance to the specials is under the limit
+    // only Manhattan distances can be split into rows and columns, the others are
+    // summed tile by tile
+    pub fn safe_region(&self, limit: usize) -> Vec<Point> {
+        if self.metric == Metric::Manhattan {
+            return self.safe_region_separable(limit);
+        }
+        let mut result = vec![];
         for i in 0..self.length {
             for j in 0..self.breadth {
-                let p = Point::new(i, j);
-                if p.total_distance(&self.specials) < limit {
-                    result += 1;
+                let p = self.point_at(i, j);
+                if p.total_distance(&self.specials, self.metric) < limit {
+                    result.push(p);
+                }
+            }
+        }
+        result
+    }
+
+    // Manhattan distance is the sum of an x and a y distance, so the total distance
+    // of a tile is the sum over its column plus the sum over its row.
+    fn safe_region_separable(&self, limit: usize) -> Vec<Point> {
+        let column_sums: Vec<usize> = (0..self.length)
+            .map(|i| {
+                let x = self.point_at(i, 0).x;
+                self.specials
+                    .iter()
+                    .map(|special| (special.x - x).unsigned_abs() as usize)
+                    .sum()
+            })
+            .collect();
+        let row_sums: Vec<usize> = (0..self.breadth)
+            .map(|j| {
+                let y = self.point_at(0, j).y;
+                self.specials
+                    .iter()
+                    .map(|special| (special.y - y).unsigned_abs() as usize)
+                    .sum()
+            })
+            .collect();
+
+        let mut result = vec![];
+        for (i, column_sum) in column_sums.iter().enumerate() {
+            for (j, row_sum) in row_sums.iter().enumerate() {
+                if column_sum + row_sum < limit {
+                    result.push(self.point_at(i, j));
                 }
             }
         }
         result
     }
 
-    fn get_tile(&self, x: usize, y: usize) -> Option<Point> {
-        self.tiles[x + y * self.length]
+    fn point_at(&self, x: usize, y: usize) -> Point {
+        Point::new(self.origin.x + x as i64, self.origin.y + y as i64)
+    }
+
+    fn get_tile(&self, x: usize, y: usize) -> Owner {
+        *self.tiles.get(x, y)
     }
 
-    fn set_tile(&mut self, p: Option<Point>, x: usize, y: usize) {
-        self.tiles[x + y * self.length] = p;
+    fn set_tile(&mut self, p: Owner, x: usize, y: usize) {
+        self.tiles.set(x, y, p);
     }
 
     fn is_edge(&self, x: usize, y: usize) -> bool {
@@ -89,138 +252,460 @@ impl Grid {
     }
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
-struct Point {
-    x: usize,
-    y: usize,
-}
+impl FromStr for Point {
+    type Err = AdventError;
 
-impl Point {
-    #[allow(dead_code)]
-    fn parse(s: &str) -> Point {
+    fn from_str(s: &str) -> Result<Point, AdventError> {
         lazy_static! {
-            static ref RE: Regex = Regex::new("(?P<x>[0-9]*), (?P<y>[0-9]*)").unwrap();
+            static ref RE: Regex = Regex::new("^(?P<x>-?[0-9]+), (?P<y>-?[0-9]+)$").unwrap();
         }
 
-        let caps = RE.captures(s).unwrap();
-        let x: usize = usize::from_str(&caps["x"]).unwrap();
-        let y: usize = usize::from_str(&caps["y"]).unwrap();
-        Point { x, y }
+        let caps = RE
+            .captures(s)
+            .ok_or_else(|| AdventError::Parse(s.to_string()))?;
+        let x = i64::from_str(&caps["x"]).map_err(|_| AdventError::Parse(s.to_string()))?;
+        let y = i64::from_str(&caps["y"]).map_err(|_| AdventError::Parse(s.to_string()))?;
+        Ok(Point { x, y })
     }
+}
 
-    fn new(x: usize, y: usize) -> Point {
-        Point { x, y }
-    }
+// The same analysis over a volume, using Manhattan distance
+#[derive(Debug)]
+pub struct Grid3 {
+    tiles: Vec<Option<Point3>>,
+    specials: Vec<Point3>,
+    length: usize,
+    breadth: usize,
+    depth: usize,
+    origin: Point3,
+}
 
-    fn nearest_point(&self, points: &[Point]) -> Option<Point> {
-        let mut min_distance = usize::max_value();
-        let mut closest: Option<Point> = None;
-        for point in points.iter() {
-            let distance = self.distance(&point);
-            if distance == min_distance {
-                closest = None;
+impl Grid3 {
+    pub fn new(input: &str) -> Result<Grid3, AdventError> {
+        let specials: Vec<Point3> = input
+            .lines()
+            .map(Point3::from_str)
+            .collect::<Result<_, _>>()?;
+        if specials.is_empty() {
+            return Err(AdventError::Parse(input.to_string()));
+        }
+
+        let min = |f: fn(&Point3) -> i64| specials.iter().map(f).min().unwrap();
+        let max = |f: fn(&Point3) -> i64| specials.iter().map(f).max().unwrap();
+        let origin = Point3::new(min(|p| p.x), min(|p| p.y), min(|p| p.z));
+        let length = (max(|p| p.x) - origin.x + 1) as usize;
+        let breadth = (max(|p| p.y) - origin.y + 1) as usize;
+        let depth = (max(|p| p.z) - origin.z + 1) as usize;
+
+        let mut grid = Grid3 {
+            tiles: vec![None; length * breadth * depth],
+            specials,
+            length,
+            breadth,
+            depth,
+            origin,
+        };
+        for i in 0..length {
+            for j in 0..breadth {
+                for k in 0..depth {
+                    let nearest = grid.point_at(i, j, k).nearest_point(&grid.specials);
+                    grid.tiles[i + j * length + k * length * breadth] = nearest;
+                }
             }
-            if distance < min_distance {
-                closest = Some(*point);
-                min_distance = distance;
+        }
+        Ok(grid)
+    }
+
+    pub fn largest_safe_area(&self, limit: usize) -> usize {
+        let mut result = 0;
+        for i in 0..self.length {
+            for j in 0..self.breadth {
+                for k in 0..self.depth {
+                    if self.point_at(i, j, k).total_distance(&self.specials) < limit {
+                        result += 1;
+                    }
+                }
             }
         }
-        closest
+        result
+    }
+
+    fn point_at(&self, x: usize, y: usize, z: usize) -> Point3 {
+        Point3::new(
+            self.origin.x + x as i64,
+            self.origin.y + y as i64,
+            self.origin.z + z as i64,
+        )
+    }
+
+    // every tile in the volume along with the special nearest to it, if there is just one
+    pub fn tiles(&self) -> impl Iterator<Item = (Point3, Option<Point3>)> + '_ {
+        (0..self.depth).flat_map(move |k| {
+            (0..self.breadth).flat_map(move |j| {
+                (0..self.length).map(move |i| (self.point_at(i, j, k), self.get_tile(i, j, k)))
+            })
+        })
+    }
+
+    fn get_tile(&self, x: usize, y: usize, z: usize) -> Option<Point3> {
+        self.tiles[x + y * self.length + z * self.length * self.breadth]
+    }
+}
+
+impl FromStr for Point3 {
+    type Err = AdventError;
+
+    fn from_str(s: &str) -> Result<Point3, AdventError> {
+        lazy_static! {
+            static ref RE: Regex =
+                Regex::new("^(?P<x>-?[0-9]+), (?P<y>-?[0-9]+), (?P<z>-?[0-9]+)$").unwrap();
+        }
+
+        let caps = RE
+            .captures(s)
+            .ok_or_else(|| AdventError::Parse(s.to_string()))?;
+        let x = i64::from_str(&caps["x"]).map_err(|_| AdventError::Parse(s.to_string()))?;
+        let y = i64::from_str(&caps["y"]).map_err(|_| AdventError::Parse(s.to_string()))?;
+        let z = i64::from_str(&caps["z"]).map_err(|_| AdventError::Parse(s.to_string()))?;
+        Ok(Point3 { x, y, z })
     }
+}
 
-    fn total_distance(&self, points: &[Point]) -> usize {
-        points.iter().map(|point| self.distance(point)).sum()
+pub struct Day06;
+
+impl Day for Day06 {
+    fn part1(&self, input: &str) -> Result<String, AdventError> {
+        Ok(Grid::new(input)?.largest_internal_area().to_string())
     }
 
-    #[allow(dead_code)]
-    fn distance(&self, other: &Point) -> usize {
-        usize::max(self.x, other.x) - usize::min(self.x, other.x) + usize::max(self.y, other.y)
-            - usize::min(self.y, other.y)
+    fn part2(&self, input: &str) -> Result<String, AdventError> {
+        Ok(Grid::new(input)?.largest_safe_area(10000).to_string())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::Day06;
+    use super::{Metric, Owner, Point, Point3};
+    use crate::day::Day;
+    use crate::error::AdventError;
+    use crate::input::read_input;
+    use crate::test_util::Seeded;
+
+    #[test]
+    fn test_day() {
+        assert_eq!(Ok(String::from("3223")), Day06.part1(&REAL_INPUT));
+        assert_eq!(Ok(String::from("40495")), Day06.part2(&REAL_INPUT));
+        assert_eq!(
+            Err(AdventError::Parse(String::from("1 1"))),
+            Day06.part1("1 1")
+        );
+    }
+
     #[test]
     fn test_grid() {
-        let grid = super::Grid::new(TEST_INPUT);
+        let grid = super::Grid::new(TEST_INPUT).unwrap();
         assert_eq!(6, grid.specials.len());
-        assert_eq!(9, grid.length);
-        assert_eq!(10, grid.breadth);
+        assert_eq!(8, grid.length);
+        assert_eq!(9, grid.breadth);
+        assert_eq!(Point::new(1, 1), grid.origin);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Ok(Point::new(1, -2)), "1, -2".parse());
+        assert_eq!(
+            Err(AdventError::Parse(String::from(", 4"))),
+            ", 4".parse::<Point>()
+        );
+        assert_eq!(
+            Err(AdventError::Parse(String::from("abc, def"))),
+            "abc, def".parse::<Point>()
+        );
+        assert!("1,2".parse::<Point>().is_err());
+        assert!("99999999999999999999, 1".parse::<Point>().is_err());
+        assert!(super::Grid::new("1, 1\n2, x").is_err());
+        assert!(super::Grid::new("").is_err());
+        assert!(super::Grid3::new("1, 1").is_err());
+    }
+
+    #[test]
+    fn test_offset_grid() {
+        let grid = super::Grid::new("1000, 1000\n1005, 1002\n1002, 1005\n1004, 1004").unwrap();
+        assert_eq!(6, grid.length);
+        assert_eq!(6, grid.breadth);
+        assert_eq!(36, grid.tiles.iter().count());
+        assert_eq!(Owner::Single(Point::new(1000, 1000)), grid.get_tile(0, 0));
+        assert_eq!(Owner::Single(Point::new(1004, 1004)), grid.get_tile(4, 4));
+
+        let grid = super::Grid::new("-3, -2\n2, 1\n-1, 3\n0, 0").unwrap();
+        assert_eq!(6, grid.length);
+        assert_eq!(6, grid.breadth);
+        assert_eq!(Point::new(-3, -2), grid.origin);
+        assert_eq!((Point::new(-3, -2), Point::new(2, 3)), grid.bounds());
+        assert_eq!(Owner::Single(Point::new(-3, -2)), grid.get_tile(0, 0));
+        assert_eq!(Owner::Single(Point::new(0, 0)), grid.get_tile(3, 2));
+    }
+
+    #[test]
+    fn test_bounds() {
+        let grid = super::Grid::new(TEST_INPUT).unwrap();
+        assert_eq!((Point::new(1, 1), Point::new(8, 9)), grid.bounds());
+        let grid = super::Grid::new("4, 7").unwrap();
+        assert_eq!((Point::new(4, 7), Point::new(4, 7)), grid.bounds());
     }
 
     #[test]
     fn test_largest_internal_area() {
-        let grid = super::Grid::new(TEST_INPUT);
+        let grid = super::Grid::new(TEST_INPUT).unwrap();
         assert_eq!(17, grid.largest_internal_area());
 
-        let grid = super::Grid::new(REAL_INPUT);
+        let grid = super::Grid::new(&REAL_INPUT).unwrap();
         assert_eq!(3223, grid.largest_internal_area());
     }
 
+    #[test]
+    fn test_finite_areas() {
+        let grid = super::Grid::new(TEST_INPUT).unwrap();
+        let areas = grid.finite_areas();
+        assert_eq!(2, areas.len());
+        assert_eq!(9, areas[&Point::new(3, 4)]);
+        assert_eq!(17, areas[&Point::new(5, 5)]);
+
+        let grid = super::Grid::new("1, 1\n4, 4").unwrap();
+        assert!(grid.finite_areas().is_empty());
+        assert_eq!(0, grid.largest_internal_area());
+    }
+
+    #[test]
+    fn test_without() {
+        let grid = super::Grid::new(TEST_INPUT).unwrap();
+        let without = grid.without(Point::new(5, 5)).unwrap();
+        assert_eq!(5, without.specials.len());
+        assert_eq!(6, grid.specials.len());
+        assert_ne!(
+            grid.largest_internal_area(),
+            without.largest_internal_area()
+        );
+        assert_eq!(
+            Some((Point::new(3, 4), 12)),
+            without.largest_internal_area_point()
+        );
+        assert_eq!(grid.bounds(), without.bounds());
+
+        let without = grid.without(Point::new(8, 9)).unwrap();
+        assert_eq!((Point::new(1, 1), Point::new(8, 6)), without.bounds());
+        // excluding a point that isn't a special changes nothing
+        let same = grid.without(Point::new(2, 2)).unwrap();
+        assert_eq!(grid.render(), same.render());
+
+        let single = super::Grid::new("3, 4").unwrap();
+        assert!(single.without(Point::new(3, 4)).is_none());
+        assert!(single.without(Point::new(1, 1)).is_some());
+    }
+
+    #[test]
+    fn test_largest_internal_area_point() {
+        let grid = super::Grid::new(TEST_INPUT).unwrap();
+        assert_eq!(
+            Some((Point::new(5, 5), 17)),
+            grid.largest_internal_area_point()
+        );
+
+        let grid = super::Grid::new("1, 1\n4, 4").unwrap();
+        assert_eq!(None, grid.largest_internal_area_point());
+
+        // the two inner points own mirror images of the same region
+        let input = "0, 0\n10, 0\n0, 10\n10, 10\n0, 5\n10, 5\n5, 0\n5, 10\n6, 5\n4, 5";
+        let grid = super::Grid::new(input).unwrap();
+        assert_eq!(10, grid.finite_areas()[&Point::new(6, 5)]);
+        assert_eq!(
+            Some((Point::new(4, 5), 10)),
+            grid.largest_internal_area_point()
+        );
+    }
+
+    #[test]
+    fn test_tiles() {
+        let grid = super::Grid::new(TEST_INPUT).unwrap();
+        let tiles: Vec<(Point, Owner)> = grid.tiles().collect();
+        assert_eq!(grid.length * grid.breadth, tiles.len());
+        assert_eq!(
+            (Point::new(1, 1), Owner::Single(Point::new(1, 1))),
+            tiles[0]
+        );
+        assert!(tiles.contains(&(Point::new(5, 2), Owner::Single(Point::new(5, 5)))));
+        let contested = tiles
+            .iter()
+            .filter(|(_, owner)| *owner == Owner::Tied)
+            .count();
+        assert_eq!(grid.contested_tiles(), contested);
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = super::Grid::new(TEST_INPUT).unwrap();
+        let rendering = grid.render();
+        let rows: Vec<&str> = rendering.lines().collect();
+        assert_eq!(9, rows.len());
+        assert_eq!("Aaaa.ccc", rows[0]);
+        assert_eq!("adddeccC", rows[2]);
+        assert_eq!("b.deEeec", rows[4]);
+        assert_eq!("bb.ffffF", rows[8]);
+    }
+
+    #[test]
+    fn test_safe_region() {
+        let grid = super::Grid::new(TEST_INPUT).unwrap();
+        let region = grid.safe_region(32);
+        assert_eq!(16, region.len());
+        assert_eq!(grid.largest_safe_area(32), region.len());
+        assert!(region.contains(&Point::new(4, 3)));
+        assert!(!region.contains(&Point::new(1, 1)));
+    }
+
+    #[test]
+    fn test_large_safe_area() {
+        let mut seeded = Seeded::new(7);
+        let input: Vec<String> = (0..50)
+            .map(|_| format!("{}, {}", seeded.below(400), seeded.below(400)))
+            .collect();
+        let grid = super::Grid::new(&input.join("\n")).unwrap();
+        let limit = 10000;
+        let naive = grid
+            .tiles()
+            .filter(|(p, _)| p.total_distance(&grid.specials, Metric::Manhattan) < limit)
+            .count();
+        assert!(naive > 0);
+        assert_eq!(naive, grid.largest_safe_area(limit));
+    }
+
+    #[test]
+    fn test_grid3() {
+        let grid = super::Grid3::new("0, 0, 0\n4, 0, 0\n0, 4, 0\n0, 0, 4").unwrap();
+        assert_eq!((5, 5, 5), (grid.length, grid.breadth, grid.depth));
+        assert_eq!(Some(Point3::new(0, 0, 0)), grid.get_tile(1, 0, 0));
+        assert_eq!(Some(Point3::new(0, 0, 4)), grid.get_tile(1, 1, 3));
+        assert_eq!(None, grid.get_tile(2, 0, 0));
+        assert_eq!(12, Point3::new(0, 0, 0).total_distance(&grid.specials));
+        let tiles: Vec<(Point3, Option<Point3>)> = grid.tiles().collect();
+        assert_eq!(125, tiles.len());
+        assert_eq!((Point3::new(1, 0, 0), Some(Point3::new(0, 0, 0))), tiles[1]);
+        assert!(tiles.contains(&(Point3::new(1, 1, 3), Some(Point3::new(0, 0, 4)))));
+        assert!(tiles.contains(&(Point3::new(2, 0, 0), None)));
+        assert_eq!(4, grid.largest_safe_area(16));
+        assert_eq!(20, grid.largest_safe_area(20));
+    }
+
+    #[test]
+    fn test_nearest_point() {
+        let specials = vec![Point::new(0, 0), Point::new(4, 0)];
+        assert_eq!(
+            Owner::Tied,
+            Point::new(2, 0).nearest_point(&specials, Metric::Manhattan)
+        );
+        assert_eq!(
+            Owner::Tied,
+            Point::new(2, 3).nearest_point(&specials, Metric::Manhattan)
+        );
+        assert_eq!(
+            Owner::Single(Point::new(0, 0)),
+            Point::new(1, 3).nearest_point(&specials, Metric::Manhattan)
+        );
+        // a tie for a distance that is later beaten is not a tie
+        let specials = vec![Point::new(0, 0), Point::new(4, 0), Point::new(2, 1)];
+        assert_eq!(
+            Owner::Single(Point::new(2, 1)),
+            Point::new(2, 0).nearest_point(&specials, Metric::Manhattan)
+        );
+        assert_eq!(
+            Owner::None,
+            Point::new(0, 0).nearest_point(&[], Metric::Manhattan)
+        );
+        assert_eq!(
+            Some(Point::new(2, 1)),
+            Point::new(2, 0)
+                .nearest_point(&specials, Metric::Manhattan)
+                .point()
+        );
+        assert_eq!(None, Owner::Tied.point());
+    }
+
+    #[test]
+    fn test_probes() {
+        let grid = super::Grid::new(TEST_INPUT).unwrap();
+        assert_eq!(
+            Owner::Single(Point::new(3, 4)),
+            grid.nearest_special(Point::new(4, 3))
+        );
+        assert_eq!(30, grid.total_distance_from(Point::new(4, 3)));
+        assert_eq!(
+            Owner::Single(Point::new(1, 1)),
+            grid.nearest_special(Point::new(1, 1))
+        );
+        assert_eq!(Owner::Tied, grid.nearest_special(Point::new(1, 4)));
+        assert_eq!(
+            Owner::Single(Point::new(1, 1)),
+            grid.nearest_special(Point::new(-10, -10))
+        );
+        assert_eq!(
+            grid.tiles()
+                .find(|(p, _)| *p == Point::new(8, 5))
+                .unwrap()
+                .1,
+            grid.nearest_special(Point::new(8, 5))
+        );
+    }
+
+    #[test]
+    fn test_contested_tiles() {
+        let grid = super::Grid::new(TEST_INPUT).unwrap();
+        assert_eq!(8, grid.contested_tiles());
+    }
+
     #[test]
     fn test_largest_safe_area() {
-        let grid = super::Grid::new(TEST_INPUT);
+        let grid = super::Grid::new(TEST_INPUT).unwrap();
         assert_eq!(16, grid.largest_safe_area(32));
 
-        let grid = super::Grid::new(REAL_INPUT);
+        let grid = super::Grid::new(&REAL_INPUT).unwrap();
         assert_eq!(40495, grid.largest_safe_area(10000));
     }
 
+    #[test]
+    fn test_euclidean_safe_area() {
+        let grid = super::Grid::with_metric(TEST_INPUT, Metric::Euclidean).unwrap();
+        assert_eq!(55, grid.largest_safe_area(32));
+        assert_ne!(
+            super::Grid::new(TEST_INPUT).unwrap().largest_safe_area(32),
+            grid.largest_safe_area(32)
+        );
+    }
+
+    #[test]
+    fn test_chebyshev() {
+        let grid = super::Grid::with_metric(TEST_INPUT, Metric::Chebyshev).unwrap();
+        assert_eq!(10, grid.largest_internal_area());
+        assert_eq!(69, grid.largest_safe_area(32));
+    }
+
+    #[test]
+    fn test_distance_with() {
+        let a = Point::new(1, 1);
+        let b = Point::new(4, 5);
+        assert_eq!(7, a.distance_with(&b, Metric::Manhattan));
+        assert_eq!(5, a.distance_with(&b, Metric::Euclidean));
+        assert_eq!(4, a.distance_with(&b, Metric::Chebyshev));
+        assert_eq!(a.distance(&b), a.distance_with(&b, Metric::default()));
+    }
+
     const TEST_INPUT: &str = "1, 1
 1, 6
 8, 3
 3, 4
 5, 5
 8, 9";
-    const REAL_INPUT: &str = "342, 203
-79, 64
-268, 323
-239, 131
-246, 87
-161, 93
-306, 146
-43, 146
-57, 112
-241, 277
-304, 303
-143, 235
-253, 318
-97, 103
-200, 250
-67, 207
-345, 149
-133, 222
-232, 123
-156, 359
-80, 224
-51, 145
-138, 312
-339, 294
-297, 256
-163, 311
-241, 321
-126, 66
-145, 171
-359, 184
-241, 58
-108, 312
-117, 118
-101, 180
-58, 290
-324, 42
-141, 190
-270, 149
-209, 294
-296, 345
-68, 266
-233, 281
-305, 183
-245, 230
-161, 295
-335, 352
-93, 66
-227, 59
-264, 249
-116, 173";
+    lazy_static! {
+        static ref REAL_INPUT: String = read_input(6).unwrap();
+    }
 }
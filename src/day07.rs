@@ -1,213 +1,973 @@
+use crate::day::Day;
+use crate::error::AdventError;
 use regex::Regex;
-use std::cmp::Ordering;
+use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::fmt;
+use std::hash::Hash;
 
 #[derive(Debug)]
-struct Node {
-    id: char,
-    unlocks: HashSet<char>,
-    dependencies: HashSet<char>,
+struct Node<T> {
+    id: T,
+    unlocks: HashSet<T>,
+    dependencies: HashSet<T>,
 }
 
-impl Node {
-    fn new(id: char) -> Node {
+impl<T: Eq + Hash> Node<T> {
+    fn new(id: T) -> Node<T> {
         Node {
             id,
             unlocks: HashSet::new(),
             dependencies: HashSet::new(),
         }
     }
+}
 
+impl Node<char> {
     fn cost(&self, base_cost: u32) -> u32 {
         (self.id as u32) - ('A' as u32) + base_cost
     }
 }
 
-struct Graph {
-    nodes: HashMap<char, Node>,
-    exec_queue: BinaryHeap<nchar>,
+// steps are single letters by default, but any ordered id works
+// ties between available steps are broken by picking the smallest id
+pub struct Graph<T = char> {
+    nodes: HashMap<T, Node<T>>,
+    exec_queue: BinaryHeap<Reverse<T>>,
 }
 
 impl Graph {
-    #[allow(dead_code)]
-    fn new(s: &str) -> Graph {
+    pub fn new(s: &str) -> Result<Graph, AdventError> {
         lazy_static! {
             static ref RE: Regex = Regex::new("Step (?P<source>[A-Z]) must be finished before step (?P<destination>[A-Z]) can begin.").unwrap();
         }
-        let mut nodes: HashMap<char, Node> = HashMap::new();
+        Graph::with_parser(s, &RE)
+    }
+
+    // re is matched against each line, and must capture single letter steps
+    // named source and destination, where source must finish before destination
+    pub fn with_parser(s: &str, re: &Regex) -> Result<Graph, AdventError> {
+        let step = |caps: &regex::Captures, name: &str| {
+            let mut chars = caps.name(name)?.as_str().chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Some(c),
+                _ => None,
+            }
+        };
+        let mut edges: Vec<(char, char)> = Vec::new();
+        for l in s.lines() {
+            let edge = re
+                .captures(l)
+                .and_then(|caps| Some((step(&caps, "source")?, step(&caps, "destination")?)))
+                .ok_or_else(|| AdventError::Parse(l.to_string()))?;
+            edges.push(edge);
+        }
+        Graph::from_edges(&edges)
+    }
+
+    pub fn execution_order(&mut self) -> String {
+        self.execution_sequence().iter().collect()
+    }
+
+    // every order in which a single worker could complete the steps, alphabetically
+    // works from a copy of the dependencies, so the graph can still be used afterwards
+    // stops after cap orders if one is given, as the number of orders grows very fast
+    pub fn all_execution_orders(&self, cap: Option<usize>) -> Vec<String> {
+        let mut remaining: HashMap<char, usize> = self.nodes.keys().map(|id| (*id, 0)).collect();
+        for node in self.nodes.values() {
+            for unlock in node.unlocks.iter() {
+                *remaining.get_mut(unlock).unwrap() += 1;
+            }
+        }
+        let mut orders: Vec<String> = Vec::new();
+        let mut order: Vec<char> = Vec::with_capacity(self.nodes.len());
+        self.extend_orders(&mut remaining, &mut order, &mut orders, cap);
+        orders
+    }
+
+    fn extend_orders(
+        &self,
+        remaining: &mut HashMap<char, usize>,
+        order: &mut Vec<char>,
+        orders: &mut Vec<String>,
+        cap: Option<usize>,
+    ) {
+        if order.len() == self.nodes.len() {
+            orders.push(order.iter().collect());
+            return;
+        }
+        let mut available: Vec<char> = remaining
+            .iter()
+            .filter(|(id, count)| **count == 0 && !order.contains(id))
+            .map(|(id, _)| *id)
+            .collect();
+        available.sort();
+        for id in available {
+            if cap.is_some_and(|cap| orders.len() >= cap) {
+                return;
+            }
+            for unlock in self.nodes[&id].unlocks.iter() {
+                *remaining.get_mut(unlock).unwrap() -= 1;
+            }
+            order.push(id);
+            self.extend_orders(remaining, order, orders, cap);
+            order.pop();
+            for unlock in self.nodes[&id].unlocks.iter() {
+                *remaining.get_mut(unlock).unwrap() += 1;
+            }
+        }
+    }
+
+    // a step takes one second more than its cost, so A takes base_cost + 1 seconds
+    // fails with the steps left waiting if some could never start
+    pub fn execution_time(
+        &mut self,
+        num_workers: usize,
+        base_cost: u32,
+    ) -> Result<u32, AdventError> {
+        let durations: HashMap<char, u32> = self
+            .nodes
+            .values()
+            .map(|node| (node.id, node.cost(base_cost) + 1))
+            .collect();
+        self.execution_time_with_costs(num_workers, &durations)
+    }
+
+    // the steps that have to finish before this one can start
+    pub fn dependencies_of(&self, id: char) -> Option<&HashSet<char>> {
+        self.nodes.get(&id).map(|node| &node.dependencies)
+    }
+
+    // the steps waiting on this one
+    pub fn unlocks_of(&self, id: char) -> Option<&HashSet<char>> {
+        self.nodes.get(&id).map(|node| &node.unlocks)
+    }
+
+    // the steps that could start now, in the order next() hands them out
+    pub fn ready_steps(&self) -> Vec<char> {
+        let mut ready: Vec<char> = self.exec_queue.iter().map(|Reverse(id)| *id).collect();
+        ready.sort();
+        ready
+    }
+
+    pub fn step_ids(&self) -> Vec<char> {
+        let mut ids: Vec<char> = self.nodes.keys().cloned().collect();
+        ids.sort();
+        ids
+    }
+}
+
+impl Graph<String> {
+    // like Graph::new, but step names can be any word, like "Step build must be ..."
+    pub fn new_named(s: &str) -> Result<Graph<String>, AdventError> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(
+                r"Step (?P<source>\w+) must be finished before step (?P<destination>\w+) can begin."
+            )
+            .unwrap();
+        }
+        let mut edges: Vec<(String, String)> = Vec::new();
         for l in s.lines() {
-            let caps = RE.captures(l).unwrap();
-            let source: char = caps["source"].chars().next().unwrap();
-            let destination: char = caps["destination"].chars().next().unwrap();
+            let caps = RE
+                .captures(l)
+                .ok_or_else(|| AdventError::Parse(l.to_string()))?;
+            edges.push((caps["source"].to_string(), caps["destination"].to_string()));
+        }
+        Graph::from_edges(&edges)
+    }
+}
 
-            let source_node = nodes.entry(source).or_insert_with(|| Node::new(source));
-            source_node.unlocks.insert(destination);
+impl<T: Ord + Hash + Clone + fmt::Display> Graph<T> {
+    // each (a, b) edge means step a must be finished before step b can begin
+    pub fn from_edges(edges: &[(T, T)]) -> Result<Graph<T>, AdventError> {
+        let graph = Graph::from_edges_unchecked(edges);
+        if let Some(cycle) = Graph::find_cycle(&graph.nodes) {
+            return Err(AdventError::Cycle(step_names(&cycle)));
+        }
+        Ok(graph)
+    }
+
+    // like from_edges, but keeps any cycles, whose steps never become ready
+    fn from_edges_unchecked(edges: &[(T, T)]) -> Graph<T> {
+        let mut nodes: HashMap<T, Node<T>> = HashMap::new();
+        for (source, destination) in edges.iter().cloned() {
+            let source_node = nodes
+                .entry(source.clone())
+                .or_insert_with(|| Node::new(source.clone()));
+            source_node.unlocks.insert(destination.clone());
 
             let destination_node = nodes
-                .entry(destination)
+                .entry(destination.clone())
                 .or_insert_with(|| Node::new(destination));
             destination_node.dependencies.insert(source);
         }
 
-        let mut exec_queue: BinaryHeap<nchar> = BinaryHeap::new();
-        nodes
-            .values()
-            .filter(|node| node.dependencies.is_empty())
-            .for_each(|node| exec_queue.push(nchar(node.id)));
+        let mut graph = Graph {
+            nodes,
+            exec_queue: BinaryHeap::new(),
+        };
+        graph.reset();
+        graph
+    }
+
+    // completing steps removes them from the dependencies of the steps they unlock
+    // reset puts those back so the graph can be executed again
+    pub fn reset(&mut self) {
+        let mut dependencies: HashMap<T, HashSet<T>> = HashMap::new();
+        for node in self.nodes.values() {
+            for unlock in node.unlocks.iter() {
+                dependencies.entry(unlock.clone())
+                    .or_default()
+                    .insert(node.id.clone());
+            }
+        }
+        for node in self.nodes.values_mut() {
+            node.dependencies = dependencies.remove(&node.id).unwrap_or_default();
+        }
 
-        Graph { nodes, exec_queue }
+        self.exec_queue.clear();
+        for node in self.nodes.values() {
+            if node.dependencies.is_empty() {
+                self.exec_queue.push(Reverse(node.id.clone()));
+            }
+        }
     }
 
-    #[allow(dead_code)]
-    fn execution_order(&mut self) -> String {
-        let mut result: Vec<char> = Vec::with_capacity(self.nodes.len());
-        while let Some(node_id) = self.next() {
-            self.complete_node(node_id);
-            result.push(node_id);
+    // depth-first search, returning the steps of the first cycle found
+    fn find_cycle(nodes: &HashMap<T, Node<T>>) -> Option<Vec<T>> {
+        let mut ids: Vec<&T> = nodes.keys().collect();
+        ids.sort();
+        let mut visited: HashSet<&T> = HashSet::new();
+        for id in ids {
+            let mut path: Vec<&T> = vec![];
+            if let Some(cycle) = Graph::visit(nodes, id, &mut visited, &mut path) {
+                return Some(cycle);
+            }
         }
-        result.iter().collect()
+        None
     }
 
-    #[allow(dead_code)]
-    fn execution_time(&mut self, num_workers: usize, base_cost: u32) -> u32 {
-        let mut time: u32 = 0;
-        let mut workers: Vec<WorkerStatus> = vec![WorkerStatus::Idle; num_workers];
-        loop {
-            // check if worker is free and assign if so
-            for i in 0..workers.len() {
-                if workers[i] == WorkerStatus::Idle {
-                    if let Some(node_id) = self.next() {
-                        let node = &self.nodes[&node_id];
-                        let completion_time = time + node.cost(base_cost);
-                        workers[i] = WorkerStatus::Working(node_id, completion_time);
-                    }
-                }
+    fn visit<'a>(
+        nodes: &'a HashMap<T, Node<T>>,
+        id: &'a T,
+        visited: &mut HashSet<&'a T>,
+        path: &mut Vec<&'a T>,
+    ) -> Option<Vec<T>> {
+        if let Some(start) = path.iter().position(|step| *step == id) {
+            return Some(path[start..].iter().map(|step| (*step).clone()).collect());
+        }
+        if !visited.insert(id) {
+            return None;
+        }
+        path.push(id);
+        let mut unlocks: Vec<&T> = nodes[id].unlocks.iter().collect();
+        unlocks.sort();
+        for unlock in unlocks {
+            if let Some(cycle) = Graph::visit(nodes, unlock, visited, path) {
+                return Some(cycle);
             }
-            // if all workers are idle, there is no work left
-            let free_workers = workers
-                .iter()
-                .filter(|status| **status == WorkerStatus::Idle)
-                .count();
-            if free_workers == workers.len() {
-                break;
+        }
+        path.pop();
+        None
+    }
+
+    // Graphviz DOT, with one "source -> destination" line per dependency, sorted
+    pub fn to_dot(&self) -> String {
+        let mut lines: Vec<String> = Vec::new();
+        for node in self.nodes.values() {
+            for unlock in node.unlocks.iter() {
+                lines.push(format!("    {} -> {};", node.id, unlock));
             }
-            // check if worker has completed their work
-            for i in 0..workers.len() {
-                match workers[i] {
-                    WorkerStatus::Idle => (),
-                    WorkerStatus::Working(node, completion_time) => {
-                        if time == completion_time {
-                            self.complete_node(node);
-                            workers[i] = WorkerStatus::Idle;
-                        }
+        }
+        lines.sort();
+        format!("digraph {{\n{}\n}}\n", lines.join("\n"))
+    }
+
+    // groups of steps connected by dependencies in either direction,
+    // ordered by their smallest step. A well formed pipeline has just one
+    pub fn components(&self) -> Vec<HashSet<T>> {
+        let mut neighbours: HashMap<&T, Vec<&T>> = HashMap::new();
+        for node in self.nodes.values() {
+            for unlock in node.unlocks.iter() {
+                neighbours.entry(&node.id)
+                    .or_default()
+                    .push(unlock);
+                neighbours.entry(unlock)
+                    .or_default()
+                    .push(&node.id);
+            }
+        }
+
+        let mut ids: Vec<&T> = self.nodes.keys().collect();
+        ids.sort();
+        let mut seen: HashSet<&T> = HashSet::new();
+        let mut components: Vec<HashSet<T>> = Vec::new();
+        for id in ids {
+            if !seen.insert(id) {
+                continue;
+            }
+            let mut component: HashSet<T> = HashSet::new();
+            let mut stack: Vec<&T> = vec![id];
+            while let Some(current) = stack.pop() {
+                component.insert(current.clone());
+                for next in neighbours.get(current).into_iter().flatten() {
+                    if seen.insert(next) {
+                        stack.push(next);
                     }
                 }
             }
-            // time moves on
-            time += 1;
+            components.push(component);
         }
-        time
+        components
     }
 
-    fn complete_node(&mut self, node_id: char) {
-        let node = &self.nodes[&node_id];
+    // the order in which the steps are completed by a single worker
+    pub fn execution_sequence(&mut self) -> Vec<T> {
+        let mut result: Vec<T> = Vec::with_capacity(self.nodes.len());
+        while let Some(node_id) = self.next() {
+            self.complete_node(&node_id);
+            result.push(node_id);
+        }
+        result
+    }
+
+    // costs gives the number of seconds each step takes, missing steps take none
+    pub fn execution_time_with_costs(
+        &mut self,
+        num_workers: usize,
+        costs: &HashMap<T, u32>,
+    ) -> Result<u32, AdventError> {
+        self.execution_time_with(num_workers, |id| costs.get(&id).cloned().unwrap_or(0))
+    }
+
+    // cost_fn gives the number of seconds each step takes
+    pub fn execution_time_with(
+        &mut self,
+        num_workers: usize,
+        cost_fn: impl Fn(T) -> u32,
+    ) -> Result<u32, AdventError> {
+        let mut scheduler = self.scheduler(num_workers, cost_fn);
+        scheduler.run()?;
+        Ok(scheduler.current_time())
+    }
+
+    // when each step starts and ends, in the order they were started
+    pub fn schedule(
+        &mut self,
+        num_workers: usize,
+        cost_fn: impl Fn(T) -> u32,
+    ) -> Result<Vec<(T, u32, u32)>, AdventError> {
+        let mut scheduler = self.scheduler(num_workers, cost_fn);
+        scheduler.run()?;
+        Ok(scheduler.started)
+    }
+
+    // runs the steps a second at a time, for callers that want to look in between
+    pub fn scheduler<F: Fn(T) -> u32>(
+        &mut self,
+        num_workers: usize,
+        cost_fn: F,
+    ) -> Scheduler<'_, T, F> {
+        let capacity = self.nodes.len();
+        Scheduler {
+            graph: self,
+            workers: vec![WorkerStatus::Idle; num_workers],
+            time: 0,
+            cost_fn,
+            started: Vec::with_capacity(capacity),
+        }
+    }
+
+    fn complete_node(&mut self, node_id: &T) {
+        let node = &self.nodes[node_id];
         let unlocks = node.unlocks.clone();
         for unlock in unlocks.iter() {
-            let unlocked_node = self.nodes.get_mut(&unlock).unwrap();
-            unlocked_node.dependencies.remove(&node_id);
+            let unlocked_node = self.nodes.get_mut(unlock).unwrap();
+            unlocked_node.dependencies.remove(node_id);
             if unlocked_node.dependencies.is_empty() {
-                let nc = nchar(*unlock);
-                self.exec_queue.push(nc);
+                self.exec_queue.push(Reverse(unlock.clone()));
             }
         }
     }
 }
 
-impl Iterator for Graph {
-    type Item = char;
+// hands out the smallest of the steps that are ready, see ready_steps
+// a step only becomes ready once complete_node has been called on its dependencies
+impl<T: Ord> Iterator for Graph<T> {
+    type Item = T;
 
-    fn next(&mut self) -> Option<char> {
-        match self.exec_queue.pop() {
-            Some(nc) => Some(nc.0),
-            None => None,
-        }
+    fn next(&mut self) -> Option<T> {
+        self.exec_queue.pop().map(|Reverse(id)| id)
     }
 }
 
-// nchar is a newtype of char
-// the only thing it does is reversing the order of comparison
-// this makes the max-heap BinaryHeap into a min-heap
-#[allow(non_camel_case_types)]
-#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
-struct nchar(pub char);
-
-impl Ord for nchar {
-    fn cmp(&self, other: &nchar) -> Ordering {
-        if self.0 == other.0 {
-            Ordering::Equal
-        } else if self.0 < other.0 {
-            Ordering::Greater
-        } else {
-            Ordering::Less
+// a worker is either idle or working on a step until the given second
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum WorkerStatus<T> {
+    Idle,
+    Working(T, u32),
+}
+
+pub struct Scheduler<'a, T, F> {
+    graph: &'a mut Graph<T>,
+    workers: Vec<WorkerStatus<T>>,
+    time: u32,
+    cost_fn: F,
+    // (step, start, end) for every step started so far
+    started: Vec<(T, u32, u32)>,
+}
+
+impl<'a, T: Ord + Hash + Clone + fmt::Display, F: Fn(T) -> u32> Scheduler<'a, T, F> {
+    // finishes the work due this second and hands out new work, then moves on a second
+    // false once every worker is idle with nothing left to start, and time stays put
+    pub fn tick(&mut self) -> bool {
+        // Work finishing now is completed before anything new is assigned, so a
+        // worker can pick up a new step in the same second it finishes one.
+        // Steps taking no time finish as soon as they are assigned and may
+        // unlock more steps, so repeat until nothing else finishes this second.
+        loop {
+            // check if worker has completed their work
+            for worker in self.workers.iter_mut() {
+                let finished = match worker {
+                    WorkerStatus::Idle => None,
+                    WorkerStatus::Working(node, completion_time) => {
+                        if self.time >= *completion_time {
+                            Some(node.clone())
+                        } else {
+                            None
+                        }
+                    }
+                };
+                if let Some(node) = finished {
+                    self.graph.complete_node(&node);
+                    *worker = WorkerStatus::Idle;
+                }
+            }
+            // check if worker is free and assign if so
+            for worker in self.workers.iter_mut() {
+                if *worker == WorkerStatus::Idle {
+                    if let Some(node_id) = self.graph.next() {
+                        let completion_time = self.time + (self.cost_fn)(node_id.clone());
+                        *worker = WorkerStatus::Working(node_id.clone(), completion_time);
+                        self.started.push((node_id, self.time, completion_time));
+                    }
+                }
+            }
+            let time = self.time;
+            let finishing_now = self.workers.iter().any(|status| match status {
+                WorkerStatus::Idle => false,
+                WorkerStatus::Working(_, completion_time) => *completion_time <= time,
+            });
+            if !finishing_now {
+                break;
+            }
+        }
+        // if all workers are idle, there is no work left
+        if self
+            .workers
+            .iter()
+            .all(|status| *status == WorkerStatus::Idle)
+        {
+            return false;
+        }
+        // time moves on
+        self.time += 1;
+        true
+    }
+
+    // ticks until there is nothing left to start
+    // fails with the steps that never started, in order, if that leaves some undone
+    pub fn run(&mut self) -> Result<(), AdventError> {
+        while self.tick() {}
+        let started: HashSet<&T> = self.started.iter().map(|(id, _, _)| id).collect();
+        let mut stuck: Vec<T> = self
+            .graph
+            .nodes
+            .keys()
+            .filter(|id| !started.contains(id))
+            .cloned()
+            .collect();
+        if stuck.is_empty() {
+            return Ok(());
         }
+        stuck.sort();
+        Err(AdventError::Deadlock(step_names(&stuck)))
     }
+
+    pub fn current_time(&self) -> u32 {
+        self.time
+    }
+
+    pub fn workers(&self) -> &[WorkerStatus<T>] {
+        &self.workers
+    }
+}
+
+// the steps as the errors name them
+fn step_names<T: fmt::Display>(steps: &[T]) -> Vec<String> {
+    steps.iter().map(|step| step.to_string()).collect()
 }
 
-impl PartialOrd for nchar {
-    fn partial_cmp(&self, other: &nchar) -> Option<Ordering> {
-        Some(self.cmp(other))
+pub struct Day07;
+
+impl Day for Day07 {
+    fn part1(&self, input: &str) -> Result<String, AdventError> {
+        Ok(steps_graph(input)?.execution_order())
+    }
+
+    // five workers, and each step takes 60 seconds more than its letter's position
+    fn part2(&self, input: &str) -> Result<String, AdventError> {
+        Ok(steps_graph(input)?.execution_time(5, 60)?.to_string())
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
-enum WorkerStatus {
-    Idle,
-    Working(char, u32),
+// there's no order or time to give for an input without any steps
+fn steps_graph(input: &str) -> Result<Graph, AdventError> {
+    let graph = Graph::new(input)?;
+    if graph.step_ids().is_empty() {
+        return Err(AdventError::NoAnswer(String::from("no steps in the input")));
+    }
+    Ok(graph)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Graph, Node};
+    use super::Day07;
+    use super::{Graph, Node, WorkerStatus};
+    use crate::day::Day;
+    use crate::error::AdventError;
+    use crate::input::read_input;
+    use regex::Regex;
+    use std::collections::HashMap;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_day() {
+        assert_eq!(
+            Ok(String::from("BHMOTUFLCPQKWINZVRXAJDSYEG")),
+            Day07.part1(&REAL_INPUT)
+        );
+        assert_eq!(Ok(String::from("877")), Day07.part2(&REAL_INPUT));
+        let no_steps = Err(AdventError::NoAnswer(String::from("no steps in the input")));
+        assert_eq!(no_steps, Day07.part1(""));
+        assert_eq!(no_steps, Day07.part2(""));
+    }
 
     #[test]
     fn test_grid() {
-        let graph = Graph::new(TEST_INPUT);
-        assert_eq!(6, graph.nodes.len());
-        let node_c = graph.nodes.get(&'C').unwrap();
-        let node_e = graph.nodes.get(&'E').unwrap();
-        assert_eq!(true, node_c.dependencies.is_empty());
-        assert_eq!(2, node_c.unlocks.len());
-        assert_eq!(true, node_c.unlocks.contains(&'A'));
-        assert_eq!(true, node_c.unlocks.contains(&'F'));
-
-        assert_eq!(3, node_e.dependencies.len());
-        assert_eq!(true, node_e.unlocks.is_empty());
-        assert_eq!(true, node_e.dependencies.contains(&'B'));
-        assert_eq!(true, node_e.dependencies.contains(&'D'));
-        assert_eq!(true, node_e.dependencies.contains(&'F'));
+        let graph = Graph::new(TEST_INPUT).unwrap();
+        assert_eq!(vec!['A', 'B', 'C', 'D', 'E', 'F'], graph.step_ids());
+        let c_dependencies = graph.dependencies_of('C').unwrap();
+        let c_unlocks = graph.unlocks_of('C').unwrap();
+        assert!(c_dependencies.is_empty());
+        assert_eq!(2, c_unlocks.len());
+        assert!(c_unlocks.contains(&'A'));
+        assert!(c_unlocks.contains(&'F'));
+
+        let e_dependencies = graph.dependencies_of('E').unwrap();
+        assert_eq!(3, e_dependencies.len());
+        assert!(graph.unlocks_of('E').unwrap().is_empty());
+        assert!(e_dependencies.contains(&'B'));
+        assert!(e_dependencies.contains(&'D'));
+        assert!(e_dependencies.contains(&'F'));
+
+        assert_eq!(None, graph.dependencies_of('Z'));
+        assert_eq!(None, graph.unlocks_of('Z'));
+    }
+
+    #[test]
+    fn test_cycle() {
+        let input = "Step A must be finished before step B can begin.
+Step B must be finished before step A can begin.";
+        assert_eq!(
+            Some(AdventError::Cycle(steps("AB"))),
+            Graph::new(input).err()
+        );
+        let input = "Step C must be finished before step A can begin.
+Step A must be finished before step B can begin.
+Step B must be finished before step D can begin.
+Step D must be finished before step A can begin.";
+        assert_eq!(
+            Some(AdventError::Cycle(steps("ABD"))),
+            Graph::new(input).err()
+        );
+        assert_eq!(
+            Some(AdventError::Parse(String::from("A before B"))),
+            Graph::new("A before B").err()
+        );
+    }
+
+    #[test]
+    fn test_with_parser() {
+        let re = Regex::new(r"^(?P<source>[A-Z]) -> (?P<destination>[A-Z])$").unwrap();
+        let input = "C -> A
+C -> F
+A -> B
+A -> D
+B -> E
+D -> E
+F -> E";
+        let mut graph = Graph::with_parser(input, &re).unwrap();
+        assert_eq!("CABDFE", graph.execution_order());
+        assert_eq!(
+            Some(AdventError::Parse(String::from("A => B"))),
+            Graph::with_parser("A => B", &re).err()
+        );
+
+        // captures must be single steps
+        let re = Regex::new(r"^(?P<source>\w+) -> (?P<destination>\w+)$").unwrap();
+        assert_eq!(
+            Some(AdventError::Parse(String::from("AB -> C"))),
+            Graph::with_parser("AB -> C", &re).err()
+        );
+        let re = Regex::new(r"^(?P<from>[A-Z]) -> (?P<destination>[A-Z])$").unwrap();
+        assert!(Graph::with_parser("A -> B", &re).is_err());
     }
 
     #[test]
     fn test_execution_order() {
-        let mut graph = Graph::new(TEST_INPUT);
+        let mut graph = Graph::new(TEST_INPUT).unwrap();
         assert_eq!("CABDFE", graph.execution_order());
 
-        let mut graph = Graph::new(REAL_INPUT);
+        let mut graph = Graph::new(&REAL_INPUT).unwrap();
         assert_eq!("BHMOTUFLCPQKWINZVRXAJDSYEG", graph.execution_order());
     }
 
     #[test]
     fn test_execution_time() {
-        let mut graph = Graph::new(TEST_INPUT);
-        assert_eq!(15, graph.execution_time(2, 0));
-        let mut graph = Graph::new(REAL_INPUT);
-        assert_eq!(877, graph.execution_time(5, 60));
+        let mut graph = Graph::new(TEST_INPUT).unwrap();
+        assert_eq!(15, graph.execution_time(2, 0).unwrap());
+        let mut graph = Graph::new(&REAL_INPUT).unwrap();
+        assert_eq!(877, graph.execution_time(5, 60).unwrap());
+    }
+
+    #[test]
+    fn test_execution_time_deadlock() {
+        // the sample, with the steps in self_loops waiting on themselves
+        let with_loops = |self_loops: &[char]| {
+            let mut edges: Vec<(char, char)> = TEST_INPUT
+                .lines()
+                .map(|line| (line.as_bytes()[5] as char, line.as_bytes()[36] as char))
+                .collect();
+            edges.extend(self_loops.iter().map(|step| (*step, *step)));
+            Graph::from_edges_unchecked(&edges)
+        };
+
+        let mut graph = with_loops(&['A', 'C']);
+        assert_eq!(
+            Err(AdventError::Deadlock(steps("ABCDEF"))),
+            graph.execution_time(2, 0)
+        );
+
+        let mut graph = with_loops(&['A']);
+        assert_eq!(
+            Err(AdventError::Deadlock(steps("ABDE"))),
+            graph.execution_time(2, 0)
+        );
+        graph.reset();
+        assert_eq!(
+            Err(AdventError::Deadlock(steps("ABDE"))),
+            graph.execution_time_with(2, |_| 1)
+        );
+        graph.reset();
+        assert_eq!(
+            Err(AdventError::Deadlock(steps("ABDE"))),
+            graph.schedule(2, |_| 1)
+        );
+
+        // without workers nothing ever starts
+        let mut graph = Graph::new(TEST_INPUT).unwrap();
+        assert_eq!(
+            Err(AdventError::Deadlock(steps("ABCDEF"))),
+            graph.schedule(0, |_| 1)
+        );
+
+        // a step handed out by next() is never completed
+        let mut graph = Graph::new(TEST_INPUT).unwrap();
+        assert_eq!(Some('C'), graph.next());
+        assert_eq!(
+            Err(AdventError::Deadlock(steps("ABCDEF"))),
+            graph.execution_time_with(2, |_| 1)
+        );
+
+        let mut graph =
+            Graph::new_named("Step fetch must be finished before step build can begin.").unwrap();
+        assert_eq!(
+            Some(AdventError::Deadlock(vec![
+                String::from("build"),
+                String::from("fetch")
+            ])),
+            graph.execution_time_with(0, |_| 1).err()
+        );
+    }
+
+    #[test]
+    fn test_ready_steps() {
+        let mut graph = Graph::new(TEST_INPUT).unwrap();
+        assert_eq!(vec!['C'], graph.ready_steps());
+        assert_eq!(Some('C'), graph.next());
+        assert!(graph.ready_steps().is_empty());
+        graph.complete_node(&'C');
+        assert_eq!(vec!['A', 'F'], graph.ready_steps());
+        assert_eq!(Some('A'), graph.next());
+        graph.complete_node(&'A');
+        assert_eq!(vec!['B', 'D', 'F'], graph.ready_steps());
+        let order: String = graph.ready_steps().iter().collect();
+        assert_eq!(
+            order,
+            (0..3).map(|_| graph.next().unwrap()).collect::<String>()
+        );
+    }
+
+    #[test]
+    fn test_scheduler() {
+        let mut graph = Graph::new(TEST_INPUT).unwrap();
+        let mut scheduler = graph.scheduler(2, |id| id as u32 - 'A' as u32 + 1);
+        assert_eq!(0, scheduler.current_time());
+        assert!(scheduler.tick());
+        assert_eq!(1, scheduler.current_time());
+        assert_eq!(
+            &[WorkerStatus::Working('C', 3), WorkerStatus::Idle],
+            scheduler.workers()
+        );
+        assert!(scheduler.tick());
+        assert!(scheduler.tick());
+        assert!(scheduler.tick());
+        assert_eq!(
+            &[WorkerStatus::Working('A', 4), WorkerStatus::Working('F', 9)],
+            scheduler.workers()
+        );
+        let mut ticks = 4;
+        while scheduler.tick() {
+            ticks += 1;
+        }
+        assert_eq!(15, ticks);
+        assert_eq!(15, scheduler.current_time());
+        assert!(!scheduler.tick());
+        assert_eq!(15, scheduler.current_time());
     }
 
     #[test]
+    fn test_schedule() {
+        let mut graph = Graph::new(TEST_INPUT).unwrap();
+        let schedule = graph.schedule(2, |id| id as u32 - 'A' as u32 + 1).unwrap();
+        assert_eq!(
+            vec![
+                ('C', 0, 3),
+                ('A', 3, 4),
+                ('F', 3, 9),
+                ('B', 4, 6),
+                ('D', 6, 10),
+                ('E', 10, 15)
+            ],
+            schedule
+        );
 
+        let mut graph = Graph::new(&REAL_INPUT).unwrap();
+        let schedule = graph.schedule(5, |id| id as u32 - 'A' as u32 + 61).unwrap();
+        assert_eq!(26, schedule.len());
+        let end = schedule.iter().map(|(_, _, end)| *end).max().unwrap();
+        let mut graph = Graph::new(&REAL_INPUT).unwrap();
+        assert_eq!(graph.execution_time(5, 60).unwrap(), end);
+    }
+
+    #[test]
+    fn test_zero_cost_steps() {
+        let mut graph = Graph::new(TEST_INPUT).unwrap();
+        assert_eq!(21, graph.execution_time(1, 0).unwrap());
+
+        let mut graph = Graph::new(TEST_INPUT).unwrap();
+        assert_eq!(Ok(0), graph.execution_time_with(1, |_| 0));
+        let mut graph = Graph::new(TEST_INPUT).unwrap();
+        let schedule = graph.schedule(1, |_| 0).unwrap();
+        let order: String = schedule.iter().map(|(id, _, _)| id).collect();
+        assert_eq!("CABDFE", order);
+        assert!(schedule
+            .iter()
+            .all(|(_, start, end)| *start == 0 && *end == 0));
+
+        let mut graph = Graph::new(TEST_INPUT).unwrap();
+        let schedule = graph
+            .schedule(1, |id| if id == 'A' { 0 } else { 1 })
+            .unwrap();
+        assert_eq!(
+            vec![
+                ('C', 0, 1),
+                ('A', 1, 1),
+                ('B', 1, 2),
+                ('D', 2, 3),
+                ('F', 3, 4),
+                ('E', 4, 5)
+            ],
+            schedule
+        );
+    }
+
+    #[test]
+    fn test_execution_time_with() {
+        let mut graph = Graph::new(TEST_INPUT).unwrap();
+        assert_eq!(Ok(4), graph.execution_time_with(2, |_| 1));
+        let mut graph = Graph::new(TEST_INPUT).unwrap();
+        assert_eq!(Ok(60), graph.execution_time_with(1, |_| 10));
+        let mut graph = Graph::new(&REAL_INPUT).unwrap();
+        assert_eq!(
+            Ok(877),
+            graph.execution_time_with(5, |id| id as u32 - 'A' as u32 + 61)
+        );
+    }
+
+    #[test]
+    fn test_named_steps() {
+        let input = "Step fetch must be finished before step build can begin.
+Step fetch must be finished before step lint can begin.
+Step build must be finished before step test can begin.
+Step lint must be finished before step test can begin.
+Step test must be finished before step deploy can begin.";
+        let mut graph = Graph::new_named(input).unwrap();
+        assert_eq!(
+            vec!["fetch", "build", "lint", "test", "deploy"],
+            graph.execution_sequence()
+        );
+
+        let costs: HashMap<String, u32> = vec![
+            ("fetch", 2),
+            ("build", 10),
+            ("lint", 3),
+            ("test", 5),
+            ("deploy", 1),
+        ]
+        .into_iter()
+        .map(|(id, cost)| (id.to_string(), cost))
+        .collect();
+        let mut graph = Graph::new_named(input).unwrap();
+        assert_eq!(Ok(18), graph.execution_time_with_costs(2, &costs));
+        let mut graph = Graph::new_named(input).unwrap();
+        assert_eq!(Ok(21), graph.execution_time_with_costs(1, &costs));
+
+        let input = "Step a1 must be finished before step b2 can begin.
+Step b2 must be finished before step a1 can begin.";
+        assert_eq!(
+            Some(AdventError::Cycle(vec![
+                String::from("a1"),
+                String::from("b2")
+            ])),
+            Graph::new_named(input).err()
+        );
+        assert_eq!(
+            Some(AdventError::Parse(String::from("fetch -> build"))),
+            Graph::new_named("fetch -> build").err()
+        );
+
+        // single letter steps order the same way as before
+        let mut graph = Graph::new_named(TEST_INPUT).unwrap();
+        assert_eq!(
+            vec!["C", "A", "B", "D", "F", "E"],
+            graph.execution_sequence()
+        );
+    }
+
+    #[test]
+    fn test_from_edges() {
+        let edges = [
+            ('C', 'A'),
+            ('C', 'F'),
+            ('A', 'B'),
+            ('A', 'D'),
+            ('B', 'E'),
+            ('D', 'E'),
+            ('F', 'E'),
+        ];
+        let mut graph = Graph::from_edges(&edges).unwrap();
+        assert_eq!("CABDFE", graph.execution_order());
+        let mut graph = Graph::from_edges(&edges).unwrap();
+        assert_eq!(15, graph.execution_time(2, 0).unwrap());
+
+        assert_eq!(
+            Some(AdventError::Cycle(steps("AB"))),
+            Graph::from_edges(&[('A', 'B'), ('B', 'A')]).err()
+        );
+    }
+
+    #[test]
+    fn test_all_execution_orders() {
+        let mut graph = Graph::new(TEST_INPUT).unwrap();
+        let orders = graph.all_execution_orders(None);
+        assert_eq!(
+            vec!["CABDFE", "CABFDE", "CADBFE", "CADFBE", "CAFBDE", "CAFDBE", "CFABDE", "CFADBE"],
+            orders
+        );
+        assert_eq!("CABDFE", graph.execution_order());
+        assert_eq!(orders, graph.all_execution_orders(None));
+
+        assert_eq!(
+            vec!["CABDFE", "CABFDE", "CADBFE"],
+            graph.all_execution_orders(Some(3))
+        );
+        let graph = Graph::new(&REAL_INPUT).unwrap();
+        assert_eq!(
+            "BHMOTUFLCPQKWINZVRXAJDSYEG",
+            graph.all_execution_orders(Some(1))[0]
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut graph = Graph::new(TEST_INPUT).unwrap();
+        assert_eq!("CABDFE", graph.execution_order());
+        assert_eq!("", graph.execution_order());
+        graph.reset();
+        assert_eq!(15, graph.execution_time(2, 0).unwrap());
+        graph.reset();
+        assert_eq!("CABDFE", graph.execution_order());
+
+        let mut graph = Graph::new(&REAL_INPUT).unwrap();
+        assert_eq!("BHMOTUFLCPQKWINZVRXAJDSYEG", graph.execution_order());
+        graph.reset();
+        assert_eq!(877, graph.execution_time(5, 60).unwrap());
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let graph = Graph::new(TEST_INPUT).unwrap();
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert!(dot.contains("    C -> A;\n"));
+        assert!(dot.contains("    C -> F;\n"));
+        assert_eq!(7, dot.matches(" -> ").count());
+
+        let mut graph = Graph::new(TEST_INPUT).unwrap();
+        graph.execution_order();
+        assert_eq!(dot, graph.to_dot());
+    }
+
+    #[test]
+    fn test_components() {
+        let graph = Graph::new(TEST_INPUT).unwrap();
+        let components = graph.components();
+        assert_eq!(1, components.len());
+        assert_eq!(6, components[0].len());
+
+        let input = "Step X must be finished before step Y can begin.
+Step A must be finished before step B can begin.
+Step Y must be finished before step Z can begin.
+Step C must be finished before step B can begin.";
+        let graph = Graph::new(input).unwrap();
+        let components = graph.components();
+        assert_eq!(2, components.len());
+        assert_eq!(vec!['A', 'B', 'C'], sorted(&components[0]));
+        assert_eq!(vec!['X', 'Y', 'Z'], sorted(&components[1]));
+    }
+
+    fn sorted(ids: &HashSet<char>) -> Vec<char> {
+        let mut ids: Vec<char> = ids.iter().cloned().collect();
+        ids.sort();
+        ids
+    }
+
+    #[test]
     fn test_cost() {
         let node_a = Node::new('A');
         assert_eq!(100, node_a.cost(100));
@@ -215,6 +975,11 @@ mod tests {
         assert_eq!(125, node_z.cost(100));
     }
 
+    // single letter step names, as the errors hold them
+    fn steps(letters: &str) -> Vec<String> {
+        letters.chars().map(|c| c.to_string()).collect()
+    }
+
     const TEST_INPUT: &str = "Step C must be finished before step A can begin.
 Step C must be finished before step F can begin.
 Step A must be finished before step B can begin.
@@ -223,105 +988,7 @@ Step B must be finished before step E can begin.
 Step D must be finished before step E can begin.
 Step F must be finished before step E can begin.";
 
-    const REAL_INPUT: &str = "Step U must be finished before step A can begin.
-Step F must be finished before step Z can begin.
-Step B must be finished before step J can begin.
-Step O must be finished before step R can begin.
-Step H must be finished before step S can begin.
-Step T must be finished before step R can begin.
-Step L must be finished before step W can begin.
-Step M must be finished before step I can begin.
-Step Q must be finished before step K can begin.
-Step Z must be finished before step V can begin.
-Step C must be finished before step E can begin.
-Step W must be finished before step I can begin.
-Step K must be finished before step S can begin.
-Step I must be finished before step Y can begin.
-Step P must be finished before step V can begin.
-Step V must be finished before step X can begin.
-Step R must be finished before step E can begin.
-Step N must be finished before step E can begin.
-Step X must be finished before step J can begin.
-Step A must be finished before step J can begin.
-Step S must be finished before step G can begin.
-Step J must be finished before step E can begin.
-Step Y must be finished before step E can begin.
-Step D must be finished before step G can begin.
-Step E must be finished before step G can begin.
-Step K must be finished before step N can begin.
-Step B must be finished before step I can begin.
-Step X must be finished before step S can begin.
-Step V must be finished before step S can begin.
-Step U must be finished before step L can begin.
-Step N must be finished before step G can begin.
-Step O must be finished before step L can begin.
-Step X must be finished before step E can begin.
-Step V must be finished before step E can begin.
-Step Y must be finished before step G can begin.
-Step A must be finished before step Y can begin.
-Step M must be finished before step E can begin.
-Step F must be finished before step Q can begin.
-Step F must be finished before step X can begin.
-Step L must be finished before step C can begin.
-Step T must be finished before step L can begin.
-Step B must be finished before step C can begin.
-Step Q must be finished before step N can begin.
-Step T must be finished before step G can begin.
-Step R must be finished before step D can begin.
-Step I must be finished before step A can begin.
-Step B must be finished before step M can begin.
-Step H must be finished before step A can begin.
-Step F must be finished before step K can begin.
-Step U must be finished before step F can begin.
-Step R must be finished before step A can begin.
-Step J must be finished before step D can begin.
-Step V must be finished before step Y can begin.
-Step F must be finished before step J can begin.
-Step C must be finished before step K can begin.
-Step M must be finished before step C can begin.
-Step F must be finished before step E can begin.
-Step I must be finished before step E can begin.
-Step T must be finished before step A can begin.
-Step J must be finished before step Y can begin.
-Step R must be finished before step X can begin.
-Step W must be finished before step S can begin.
-Step V must be finished before step R can begin.
-Step U must be finished before step V can begin.
-Step C must be finished before step V can begin.
-Step F must be finished before step Y can begin.
-Step R must be finished before step G can begin.
-Step W must be finished before step N can begin.
-Step H must be finished before step N can begin.
-Step H must be finished before step Y can begin.
-Step B must be finished before step W can begin.
-Step M must be finished before step Z can begin.
-Step X must be finished before step A can begin.
-Step A must be finished before step G can begin.
-Step N must be finished before step A can begin.
-Step H must be finished before step J can begin.
-Step B must be finished before step O can begin.
-Step W must be finished before step A can begin.
-Step P must be finished before step N can begin.
-Step Z must be finished before step G can begin.
-Step W must be finished before step D can begin.
-Step D must be finished before step E can begin.
-Step W must be finished before step J can begin.
-Step N must be finished before step D can begin.
-Step C must be finished before step J can begin.
-Step B must be finished before step Y can begin.
-Step F must be finished before step P can begin.
-Step L must be finished before step P can begin.
-Step X must be finished before step G can begin.
-Step R must be finished before step Y can begin.
-Step K must be finished before step A can begin.
-Step M must be finished before step Y can begin.
-Step W must be finished before step Y can begin.
-Step F must be finished before step I can begin.
-Step L must be finished before step X can begin.
-Step R must be finished before step J can begin.
-Step V must be finished before step J can begin.
-Step V must be finished before step D can begin.
-Step H must be finished before step C can begin.
-Step O must be finished before step G can begin.
-Step P must be finished before step R can begin.";
+    lazy_static! {
+        static ref REAL_INPUT: String = read_input(7).unwrap();
+    }
 }
@@ -1,51 +1,175 @@
+use crate::day::Day;
+use crate::error::AdventError;
+use regex::Regex;
 use std::collections::HashMap;
+use std::collections::VecDeque;
 
-struct Board {
-    marbles: Vec<i32>,
-    scores: HashMap<i32, i32>,
+pub struct Board {
+    // the current marble is always at the back
+    // clockwise from the back wraps around to the front
+    marbles: VecDeque<i32>,
+    scores: HashMap<i32, i64>,
     num_players: i32,
     last_marble: i32,
+    special_every: i32,
+    step_back: usize,
 }
 
 impl Board {
-    #[allow(dead_code)]
-    fn new(num_players: i32, last_marble: i32) -> Board {
+    pub fn new(num_players: i32, last_marble: i32) -> Board {
+        Board::new_with_rules(num_players, last_marble, 23, 7)
+    }
+
+    // every marble numbered a multiple of special_every is scored along with the one
+    // step_back marbles counter-clockwise of current
+    pub fn new_with_rules(
+        num_players: i32,
+        last_marble: i32,
+        special_every: i32,
+        step_back: usize,
+    ) -> Board {
+        assert!(
+            num_players > 0 || last_marble == 0,
+            "there has to be a player to place the marbles"
+        );
+        assert!(num_players >= 0, "the number of players can't be negative");
+        assert!(last_marble >= 0, "the last marble can't be negative");
+        assert!(
+            special_every > 0,
+            "special marbles have to be numbered from 1 up"
+        );
         Board {
-            marbles: Vec::with_capacity(last_marble as usize),
+            marbles: VecDeque::with_capacity(last_marble as usize),
             scores: HashMap::with_capacity(num_players as usize),
             num_players,
             last_marble,
+            special_every,
+            step_back,
+        }
+    }
+
+    // parses the puzzle input, like "9 players; last marble is worth 23 points"
+    pub fn from_input(s: &str) -> Result<Board, AdventError> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(
+                r"(?P<players>[0-9]+) players; last marble is worth (?P<marble>[0-9]+) points"
+            )
+            .unwrap();
         }
+        let parse_error = || AdventError::Parse(s.trim().to_string());
+        let caps = RE.captures(s).ok_or_else(parse_error)?;
+        let num_players: i32 = caps["players"].parse().map_err(|_| parse_error())?;
+        let last_marble: i32 = caps["marble"].parse().map_err(|_| parse_error())?;
+        if num_players == 0 && last_marble > 0 {
+            return Err(parse_error());
+        }
+        Ok(Board::new(num_players, last_marble))
     }
 
-    #[allow(dead_code)]
-    fn winning_score(&mut self) -> i32 {
-        let mut current_index = 1;
-        let mut current_player = 2;
-        self.marbles.push(0);
-        self.marbles.push(1);
-        for marble in 2..=self.last_marble {
-            current_player = (current_player + 1) % self.num_players;
+    // 0 when nobody has scored, like in games too short to reach marble 23
+    pub fn winning_score(&mut self) -> i64 {
+        self.final_scores().values().max().cloned().unwrap_or(0)
+    }
 
-            if marble % 23 == 0 {
-                current_index = (self.marbles.len() + current_index - 7) % self.marbles.len();
-                let score = marble + self.marbles.remove(current_index);
+    // plays a fresh game and returns every player's score, including those who scored nothing
+    // players are numbered 1 to num_players, player 1 places marble 1
+    pub fn final_scores(&mut self) -> HashMap<i32, i64> {
+        self.play(|_| ());
+        self.scores.clone()
+    }
+
+    // the circle after each marble is placed, starting with just marble 0
+    // each circle is listed clockwise from marble 0, like the puzzle's example
+    pub fn play_with_trace(&mut self) -> Vec<Vec<i32>> {
+        let mut trace: Vec<Vec<i32>> = Vec::with_capacity(self.last_marble as usize + 1);
+        self.play(|marbles| {
+            // or from the current marble's clockwise neighbour once marble 0 has been scored
+            let zero = marbles.iter().position(|marble| *marble == 0).unwrap_or(0);
+            let circle = marbles
+                .iter()
+                .cycle()
+                .skip(zero)
+                .take(marbles.len())
+                .cloned()
+                .collect();
+            trace.push(circle);
+        });
+        trace
+    }
+
+    // on_turn sees the circle at the start and after every marble
+    fn play(&mut self, mut on_turn: impl FnMut(&VecDeque<i32>)) {
+        self.marbles.clear();
+        self.scores = (1..=self.num_players).map(|player| (player, 0)).collect();
+        self.marbles.push_back(0);
+        on_turn(&self.marbles);
+        for marble in 1..=self.last_marble {
+            let current_player = (marble - 1) % self.num_players + 1;
+
+            if marble % self.special_every == 0 {
+                // the marble step_back counter-clockwise is removed
+                // and the one clockwise of it becomes current
+                // a small circle is wrapped around as many times as it takes
+                // once every marble has been removed, a special marble only scores itself
+                let mut score = i64::from(marble);
+                if !self.marbles.is_empty() {
+                    let step_back = self.step_back % self.marbles.len();
+                    self.marbles.rotate_right(step_back);
+                    score += i64::from(self.marbles.pop_back().unwrap());
+                    if !self.marbles.is_empty() {
+                        self.marbles.rotate_left(1);
+                    }
+                }
                 self.scores.entry(current_player)
-                    .and_modify(|n| *n = *n + score)
+                    .and_modify(|n| *n += score)
                     .or_insert(score);
-                continue;
+            } else {
+                // the new marble goes between the ones 1 and 2 clockwise of current
+                self.marbles.rotate_left(1);
+                self.marbles.push_back(marble);
             }
-
-            current_index = (current_index + 2) % self.marbles.len();
-            self.marbles.insert(current_index, marble);
+            on_turn(&self.marbles);
         }
-        *self.scores.values().max().unwrap()
+    }
+}
+
+// part 2 plays the same game with a last marble 100 times larger
+pub fn winning_score_part2(num_players: i32, last_marble: i32) -> i64 {
+    Board::new(num_players, last_marble * 100).winning_score()
+}
+
+pub struct Day09;
+
+impl Day for Day09 {
+    fn part1(&self, input: &str) -> Result<String, AdventError> {
+        Ok(Board::from_input(input)?.winning_score().to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, AdventError> {
+        let board = Board::from_input(input)?;
+        Ok(winning_score_part2(board.num_players, board.last_marble).to_string())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::Board;
+    use super::Day09;
+    use crate::day::Day;
+    use crate::error::AdventError;
+    use crate::input::read_input;
+    use crate::test_util::Seeded;
+    #[test]
+    fn test_day() {
+        let input = read_input(9).unwrap();
+        assert_eq!(Ok(String::from("398502")), Day09.part1(&input));
+        assert_eq!(Ok(String::from("3352920421")), Day09.part2(&input));
+        assert_eq!(
+            Err(AdventError::Parse(String::from("9 players"))),
+            Day09.part1("9 players\n")
+        );
+    }
+
     #[test]
     fn test_winning_score() {
         let mut board = Board::new(9, 23);
@@ -58,9 +182,189 @@ mod tests {
         assert_eq!(2764, board.winning_score());
         let mut board = Board::new(428, 70825);
         assert_eq!(398502, board.winning_score());
+    }
+
+    #[test]
+    fn test_winning_score_part2() {
+        // the real part 2 game is only played by test_day, it takes a while
+        let mut board = Board::new(10, 161800);
+        assert_eq!(board.winning_score(), super::winning_score_part2(10, 1618));
+    }
 
-        //        Part 2 requires a LinkedList.
-        //        let mut board = Board::new(428, 7082500);
-        //        assert_eq!(398502, board.winning_score());
+    #[test]
+    fn test_final_scores() {
+        let mut board = Board::new(9, 23);
+        let scores = board.final_scores();
+        assert_eq!(9, scores.len());
+        assert_eq!(32, scores.values().sum::<i64>());
+        assert_eq!(1, scores.values().filter(|score| **score == 32).count());
+        assert_eq!(0, scores[&1]);
+        assert_eq!(32, scores[&5]);
+        assert!((1..=9).all(|player| scores.contains_key(&player)));
+
+        let mut board = Board::new(10, 1618);
+        let scores = board.final_scores();
+        assert_eq!(8317, *scores.values().max().unwrap());
+        assert_eq!(8317, scores[&10]);
+        assert_eq!(6857, scores[&2]);
+        assert_eq!(8317, board.winning_score());
+    }
+
+    #[test]
+    fn test_short_games() {
+        for last_marble in [0, 1, 22] {
+            let mut board = Board::new(9, last_marble);
+            assert_eq!(0, board.winning_score());
+            assert!(board.final_scores().values().all(|score| *score == 0));
+        }
+        let mut board = Board::new(9, 1);
+        assert_eq!(vec![vec![0], vec![0, 1]], board.play_with_trace());
+        let mut board = Board::new(0, 0);
+        assert_eq!(0, board.winning_score());
+    }
+
+    #[test]
+    fn test_new_with_rules() {
+        let mut board = Board::new_with_rules(10, 1618, 23, 7);
+        assert_eq!(8317, board.winning_score());
+
+        let mut board = Board::new_with_rules(9, 25, 5, 2);
+        let scores = board.final_scores();
+        assert_eq!(48, board.winning_score());
+        assert_eq!(
+            (18, 38, 8, 28, 48),
+            (scores[&1], scores[&2], scores[&5], scores[&6], scores[&7])
+        );
+
+        // stepping back further than the circle is long wraps around it
+        let mut board = Board::new_with_rules(9, 25, 5, 7);
+        assert_eq!(37, board.winning_score());
+
+        // every marble is special, so marble 0 goes first and the circle stays empty
+        let mut board = Board::new_with_rules(3, 5, 1, 7);
+        let scores = board.final_scores();
+        assert_eq!(
+            vec![5, 7, 3],
+            (1..=3).map(|player| scores[&player]).collect::<Vec<i64>>()
+        );
+        let trace = board.play_with_trace();
+        assert_eq!(vec![0], trace[0]);
+        assert!(trace[1..].iter().all(|circle| circle.is_empty()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_no_players() {
+        Board::new_with_rules(0, 25, 23, 7);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_no_special_marbles() {
+        Board::new_with_rules(9, 25, 0, 7);
+    }
+
+    #[test]
+    fn test_from_input() {
+        let mut board = Board::from_input("10 players; last marble is worth 1618 points").unwrap();
+        assert_eq!(10, board.num_players);
+        assert_eq!(1618, board.last_marble);
+        assert_eq!(8317, board.winning_score());
+        let mut board =
+            Board::from_input("428 players; last marble is worth 70825 points\n").unwrap();
+        assert_eq!(398502, board.winning_score());
+        assert_eq!(
+            Some(AdventError::Parse(String::from(
+                "0 players; last marble is worth 25 points"
+            ))),
+            Board::from_input("0 players; last marble is worth 25 points").err()
+        );
+        assert_eq!(
+            Some(AdventError::Parse(String::from("10 players"))),
+            Board::from_input("10 players\n").err()
+        );
+        // too many players for an i32
+        assert_eq!(
+            Some(AdventError::Parse(String::from(
+                "9999999999 players; last marble is worth 25 points"
+            ))),
+            Board::from_input("9999999999 players; last marble is worth 25 points").err()
+        );
+    }
+
+    #[test]
+    fn test_play_with_trace() {
+        let mut board = Board::new(9, 25);
+        let trace = board.play_with_trace();
+        assert_eq!(26, trace.len());
+        assert_eq!(vec![0], trace[0]);
+        assert_eq!(vec![0, 1], trace[1]);
+        assert_eq!(vec![0, 2, 1], trace[2]);
+        assert_eq!(vec![0, 2, 1, 3], trace[3]);
+        assert_eq!(vec![0, 4, 2, 1, 3], trace[4]);
+        assert_eq!(
+            vec![0, 16, 8, 17, 4, 18, 19, 2, 20, 10, 21, 5, 22, 11, 1, 12, 6, 13, 3, 14, 7, 15],
+            trace[23]
+        );
+        assert_eq!(
+            vec![
+                0, 16, 8, 17, 4, 18, 19, 2, 24, 20, 25, 10, 21, 5, 22, 11, 1, 12, 6, 13, 3, 14, 7,
+                15
+            ],
+            trace[25]
+        );
+    }
+
+    #[test]
+    fn test_large_game() {
+        // with few players every score is well past what an i32 can hold
+        let mut board = Board::new(10, 5000000);
+        let score = board.winning_score();
+        assert_eq!(71297591051, score);
+        assert!(board.scores.values().all(|n| *n > i64::from(i32::MAX)));
+    }
+
+    // a reference version that inserts and removes marbles in the middle of a Vec
+    // far too slow for part 2, but easy to check against the puzzle description
+    fn winning_score_naive(num_players: i32, last_marble: i32) -> i64 {
+        let mut marbles: Vec<i32> = vec![0];
+        let mut scores: Vec<i64> = vec![0; num_players as usize];
+        let mut current = 0;
+        for marble in 1..=last_marble {
+            if marble % 23 == 0 {
+                current = (current + marbles.len() - 7 % marbles.len()) % marbles.len();
+                let removed = marbles.remove(current);
+                scores[((marble - 1) % num_players) as usize] += i64::from(marble + removed);
+                current %= marbles.len();
+            } else {
+                current = (current + 1) % marbles.len() + 1;
+                marbles.insert(current, marble);
+            }
+        }
+        *scores.iter().max().unwrap()
+    }
+
+    // (num_players, last_marble) pairs from a fixed seed, so failures can be replayed
+    fn random_games(count: usize, seed: u64) -> Vec<(i32, i32)> {
+        let mut seeded = Seeded::new(seed);
+        (0..count)
+            .map(|_| (seeded.below(30) as i32 + 1, seeded.below(3000) as i32 + 1))
+            .collect()
+    }
+
+    #[test]
+    fn test_against_naive() {
+        assert_eq!(32, winning_score_naive(9, 25));
+        assert_eq!(8317, winning_score_naive(10, 1618));
+        for (num_players, last_marble) in random_games(200, 42) {
+            let mut board = Board::new(num_players, last_marble);
+            assert_eq!(
+                winning_score_naive(num_players, last_marble),
+                board.winning_score(),
+                "{} players; last marble is worth {} points",
+                num_players,
+                last_marble
+            );
+        }
     }
 }
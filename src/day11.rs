@@ -1,133 +1,540 @@
-use std::collections::HashMap;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::sync::OnceLock;
+use std::thread;
 
-struct Grid {
-    cells: [[i32; 300]; 300],
-    mini_grid_cache: HashMap<(usize, usize, usize), i32>,
+use crate::day::Day;
+use crate::error::AdventError;
+use crate::geometry;
+
+// power of the fuel cell at (x, y), using the puzzle's coordinates which start at 1
+pub fn power_level(x: usize, y: usize, serial_number: usize) -> i32 {
+    let rack_id = x + 10;
+    let mut power_level = rack_id * y;
+    power_level += serial_number;
+    power_level *= rack_id;
+
+    let hundreds_digit = (power_level / 100) % 10;
+
+    (hundreds_digit as i32) - 5
+}
+
+pub struct Grid {
+    // the cell at puzzle coordinate (x, y) is stored at (x - 1, y - 1)
+    cells: geometry::Grid<i32>,
+    size: usize,
+    // summed-area table, the sum at (x, y) is the total of every cell up to and including
+    // puzzle coordinate (x, y), so row and column 0 are zeroes and squares at the edge need no
+    // special case
+    // sums are kept as i64 so larger grids can't overflow them
+    // it is built by the first query that needs it and shared by all the others
+    sums: OnceLock<geometry::Grid<i64>>,
 }
 
 impl Grid {
-    #[allow(dead_code)]
-    fn new(serial_number: usize) -> Grid {
-        let mut cells: [[i32; 300]; 300] = [[0; 300]; 300];
-        for i in 0..cells.len() {
-            for j in 0..cells.len() {
-                cells[i][j] = Grid::magic_number(i, j, serial_number);
+    pub fn new(serial_number: usize) -> Grid {
+        Grid::new_sized(serial_number, 300)
+    }
+
+    pub fn new_sized(serial_number: usize, size: usize) -> Grid {
+        let mut cells: geometry::Grid<i32> = geometry::Grid::new(size, size, 0);
+        for i in 0..size {
+            for j in 0..size {
+                cells.set(i, j, power_level(i + 1, j + 1, serial_number));
             }
         }
-        let mini_grid_cache = HashMap::new();
+        Grid::from_cells(cells)
+    }
+
+    // cells has to be square
+    fn from_cells(cells: geometry::Grid<i32>) -> Grid {
         Grid {
+            size: cells.width(),
             cells,
-            mini_grid_cache,
+            sums: OnceLock::new(),
         }
     }
 
-    fn magic_number(x: usize, y: usize, serial_number: usize) -> i32 {
-        let rack_id = x + 10;
-        let mut power_level = rack_id * y;
-        power_level += serial_number;
-        power_level *= rack_id;
+    // builds the summed-area table now rather than on the first query
+    pub fn precompute_summed_area(&mut self) {
+        self.summed_area();
+    }
+
+    fn summed_area(&self) -> &geometry::Grid<i64> {
+        self.sums.get_or_init(|| {
+            let size = self.size;
+            let mut sums: geometry::Grid<i64> = geometry::Grid::new(size + 1, size + 1, 0);
+            for i in 0..size {
+                for j in 0..size {
+                    let sum =
+                        i64::from(*self.cells.get(i, j)) + sums.get(i, j + 1) + sums.get(i + 1, j)
+                            - sums.get(i, j);
+                    sums.set(i + 1, j + 1, sum);
+                }
+            }
+            sums
+        })
+    }
+
+    // the power of the cell at puzzle coordinate (x, y)
+    fn cell(&self, x: usize, y: usize) -> i32 {
+        *self.cells.get(x - 1, y - 1)
+    }
 
-        let hundreds_digit = (power_level / 100) % 10;
+    pub fn best_simple_spot(&mut self) -> Option<(usize, usize)> {
+        self.best_simple_spot_scored().map(|(spot, _)| spot)
+    }
 
-        (hundreds_digit as i32) - 5
+    // the best 3x3 square and its total power, None if the grid is smaller than that
+    pub fn best_simple_spot_scored(&mut self) -> Option<((usize, usize), i64)> {
+        self.best_spot_of_size(3)
     }
 
-    #[allow(dead_code)]
-    fn best_simple_spot(&mut self) -> (usize, usize) {
-        let mut candidate_spot: (usize, usize) = (0, 0);
-        let mut candidate_score = self.mini_grid_value(0, 0, 3);
-        for i in 0..self.cells.len() - 3 {
-            for j in 0..self.cells.len() - 3 {
-                let score = self.mini_grid_value(i, j, 3);
+    // the best square of the given size and its total power
+    // None if the size is 0 or the square doesn't fit in the grid
+    pub fn best_spot_of_size(&mut self, size: usize) -> Option<((usize, usize), i64)> {
+        if size == 0 || size > self.size {
+            return None;
+        }
+        let mut candidate_spot: (usize, usize) = (1, 1);
+        let mut candidate_score = self.mini_grid_value(1, 1, size);
+        for i in 1..=self.size - size + 1 {
+            for j in 1..=self.size - size + 1 {
+                let score = self.mini_grid_value(i, j, size);
                 if score > candidate_score {
                     candidate_score = score;
                     candidate_spot = (i, j);
                 }
             }
         }
-        candidate_spot
+        Some((candidate_spot, candidate_score))
     }
 
-    #[allow(dead_code)]
-    fn best_complex_spot(&mut self) -> (usize, usize, usize) {
-        for i in 0..self.cells.len() {
-            for j in 0..self.cells.len() {
-                let biggest_possible_square = 300 - usize::max(i, j);
-                for k in 1..=biggest_possible_square {
-                    self.mini_grid_value(i, j, k);
-                }
-            }
-        }
+    pub fn best_complex_spot(&mut self) -> Option<(usize, usize, usize)> {
+        self.best_complex_spot_scored().map(|(key, _)| key)
+    }
+
+    // the best square of any size and its total power, None if the grid is empty
+    // ties go to the first square, in the order squares() yields them
+    pub fn best_complex_spot_scored(&mut self) -> Option<((usize, usize, usize), i64)> {
+        self.squares()
+            .max_by_key(|(key, score)| (*score, Reverse(*key)))
+    }
 
-        let mut max_key = (0, 0, 1);
-        let mut max_val = &self.mini_grid_cache[&max_key];
-        for (key, value) in &self.mini_grid_cache {
-            if value > max_val {
-                max_val = value;
-                max_key = *key;
+    // the k squares of any size with the highest total power, best first
+    // squares with equal power are ordered like squares() yields them
+    pub fn top_k_spots(&self, k: usize) -> Vec<((usize, usize, usize), i64)> {
+        // the weakest square kept so far is always at the top of the heap
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        for (key, score) in self.squares() {
+            heap.push(Reverse((score, Reverse(key))));
+            if heap.len() > k {
+                heap.pop();
             }
         }
-        max_key
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((score, Reverse(key)))| (key, score))
+            .collect()
     }
 
-    fn mini_grid_value(&mut self, x: usize, y: usize, mini_grid_size: usize) -> i32 {
-        let cache_key = (x, y, mini_grid_size);
-        if mini_grid_size == 1 {
-            self.mini_grid_cache.insert(cache_key, self.cells[x][y]);
-            return self.cells[x][y];
+    // the square of any size with the lowest total power, the first one if several tie
+    pub fn worst_complex_spot(&self) -> Option<(usize, usize, usize)> {
+        self.squares()
+            .min_by_key(|(key, score)| (*score, *key))
+            .map(|(key, _)| key)
+    }
+
+    // every square that fits in the grid and its total power, as ((x, y, size), power)
+    // ordered by x, then y, then size
+    pub fn squares(&self) -> impl Iterator<Item = ((usize, usize, usize), i64)> + '_ {
+        (1..=self.size).flat_map(move |i| {
+            (1..=self.size).flat_map(move |j| {
+                let biggest_possible_square = self.size + 1 - usize::max(i, j);
+                (1..=biggest_possible_square)
+                    .map(move |k| ((i, j, k), self.mini_grid_value(i, j, k)))
+            })
+        })
+    }
+
+    // splits the rows between threads, each thread searching every 'nth' row
+    // ties are broken the same way as best_complex_spot, so both give the same answer
+    pub fn best_complex_spot_parallel(&self) -> Option<(usize, usize, usize)> {
+        if self.size == 0 {
+            return None;
         }
-        if self.mini_grid_cache.contains_key(&cache_key) {
-            return self.mini_grid_cache[&cache_key];
+        let num_threads = thread::available_parallelism().map_or(4, |n| n.get());
+        let results: Vec<((usize, usize, usize), i64)> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..num_threads)
+                .map(|t| {
+                    scope.spawn(move || {
+                        self.best_square_in_rows((t + 1..=self.size).step_by(num_threads))
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect()
+        });
+        results
+            .into_iter()
+            .max_by_key(|(key, value)| (*value, Reverse(*key)))
+            .map(|(key, _)| key)
+    }
+
+    // the first square with the highest total among those starting in the given rows
+    fn best_square_in_rows(
+        &self,
+        rows: impl Iterator<Item = usize>,
+    ) -> ((usize, usize, usize), i64) {
+        let mut max_key = (1, 1, 1);
+        let mut max_val = i64::MIN;
+        for i in rows {
+            for j in 1..=self.size {
+                let biggest_possible_square = self.size + 1 - usize::max(i, j);
+                for k in 1..=biggest_possible_square {
+                    let value = self.mini_grid_value(i, j, k);
+                    if value > max_val {
+                        max_val = value;
+                        max_key = (i, j, k);
+                    }
+                }
+            }
         }
+        (max_key, max_val)
+    }
 
-        let mut sum = 0;
-        sum += self.vector_value(x, y, x + mini_grid_size - 1, y);
-        sum += self.vector_value(x, y, x, y + mini_grid_size - 1);
-        sum += self.mini_grid_value(x + 1, y + 1, mini_grid_size - 1);
-        sum -= self.cells[x][y];
+    // one line per row from y = 1, with each cell drawn as
+    // '-' for negative power, '.' for none, '+' for 1 or 2 and '#' for 3 or 4
+    pub fn render_heatmap(&self) -> String {
+        let mut result = String::with_capacity((self.size + 1) * self.size);
+        for y in 1..=self.size {
+            for x in 1..=self.size {
+                let glyph = match self.cell(x, y) {
+                    power if power < 0 => '-',
+                    0 => '.',
+                    1 | 2 => '+',
+                    _ => '#',
+                };
+                result.push(glyph);
+            }
+            result.push('\n');
+        }
+        result
+    }
 
-        self.mini_grid_cache.insert(cache_key, sum);
-        sum
+    // total of the square with top left corner (x, y), read from the summed-area table
+    // negative when the square holds more negative power than positive
+    fn mini_grid_value(&self, x: usize, y: usize, mini_grid_size: usize) -> i64 {
+        let sums = self.summed_area();
+        let (start_x, start_y) = (x - 1, y - 1);
+        let (end_x, end_y) = (start_x + mini_grid_size, start_y + mini_grid_size);
+        sums.get(end_x, end_y) - sums.get(start_x, end_y) - sums.get(end_x, start_y)
+            + sums.get(start_x, start_y)
     }
 
-    fn vector_value(&self, start_x: usize, start_y: usize, end_x: usize, end_y: usize) -> i32 {
+    // adds up every cell from (start_x, start_y) to (end_x, end_y) one by one,
+    // to check the summed-area table against
+    #[cfg(test)]
+    fn vector_value(&self, start_x: usize, start_y: usize, end_x: usize, end_y: usize) -> i64 {
         let mut sum = 0;
         for i in start_x..=end_x {
             for j in start_y..=end_y {
-                sum += self.cells[i][j]
+                sum += i64::from(self.cell(i, j))
             }
         }
         sum
     }
 }
 
+pub struct Day11;
+
+// a 300x300 grid always has squares, of size 3 and every other
+impl Day for Day11 {
+    fn part1(&self, input: &str) -> Result<String, AdventError> {
+        let (x, y) = Grid::new(serial_number(input)?)
+            .best_simple_spot()
+            .expect("a 300x300 grid has 3x3 squares");
+        Ok(format!("{},{}", x, y))
+    }
+
+    fn part2(&self, input: &str) -> Result<String, AdventError> {
+        let (x, y, size) = Grid::new(serial_number(input)?)
+            .best_complex_spot()
+            .expect("a 300x300 grid has squares");
+        Ok(format!("{},{},{}", x, y, size))
+    }
+}
+
+// the input is the grid serial number
+fn serial_number(input: &str) -> Result<usize, AdventError> {
+    input
+        .trim()
+        .parse()
+        .map_err(|_| AdventError::Parse(input.trim().to_string()))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Grid;
+    use super::Day11;
+    use super::{power_level, Grid};
+    use crate::day::Day;
+    use crate::error::AdventError;
+    use crate::geometry;
+    use crate::input::read_input;
+    use std::cmp::Reverse;
 
     #[test]
-    fn test_magic() {
-        assert_eq!(4, Grid::magic_number(3, 5, 8));
-        assert_eq!(-5, Grid::magic_number(122, 79, 57));
-        assert_eq!(0, Grid::magic_number(217, 196, 39));
-        assert_eq!(4, Grid::magic_number(101, 153, 71))
+    fn test_day() {
+        let input = read_input(11).unwrap();
+        assert_eq!(Ok(String::from("21,41")), Day11.part1(&input));
+        assert_eq!(Ok(String::from("227,199,19")), Day11.part2(&input));
+        assert_eq!(
+            Err(AdventError::Parse(String::from("serial"))),
+            Day11.part1("serial\n")
+        );
+    }
+
+    #[test]
+    fn test_power_level() {
+        // examples from the puzzle, (x, y, serial) with x and y starting at 1
+        assert_eq!(4, power_level(3, 5, 8));
+        assert_eq!(-5, power_level(122, 79, 57));
+        assert_eq!(0, power_level(217, 196, 39));
+        assert_eq!(4, power_level(101, 153, 71));
+
+        let grid = Grid::new(57);
+        assert_eq!(power_level(122, 79, 57), grid.cell(122, 79));
+        assert_eq!(power_level(1, 1, 57), *grid.cells.get(0, 0));
+        assert_eq!(power_level(300, 300, 57), grid.cell(300, 300));
     }
 
     #[test]
     fn test_simple_spot() {
         let mut grid = Grid::new(18);
-        assert_eq!((33, 45), grid.best_simple_spot());
+        assert_eq!(Some((33, 45)), grid.best_simple_spot());
         let mut grid = Grid::new(42);
-        assert_eq!((21, 61), grid.best_simple_spot());
+        assert_eq!(Some((21, 61)), grid.best_simple_spot());
         let mut grid = Grid::new(1308);
-        assert_eq!((21, 41), grid.best_simple_spot());
+        assert_eq!(Some((21, 41)), grid.best_simple_spot());
     }
 
     #[test]
-    #[ignore]
     fn test_complex_spot() {
         let mut grid = Grid::new(1308);
-        assert_eq!((227, 199, 19), grid.best_complex_spot());
+        assert_eq!(Some((227, 199, 19)), grid.best_complex_spot());
+    }
+
+    #[test]
+    fn test_mini_grid_value() {
+        let grid = Grid::new(42);
+        assert_eq!(30, grid.mini_grid_value(21, 61, 3));
+        assert_eq!(i64::from(grid.cell(5, 7)), grid.mini_grid_value(5, 7, 1));
+        let total: i64 = grid.cells.iter().map(|cell| i64::from(*cell)).sum();
+        assert_eq!(total, grid.mini_grid_value(1, 1, 300));
+        assert!(total.abs() <= 5 * 300 * 300);
+        assert_eq!(
+            grid.vector_value(10, 20, 14, 24),
+            grid.mini_grid_value(10, 20, 5)
+        );
+    }
+
+    #[test]
+    fn test_totals_past_i32() {
+        // a single cell is as big as an i32 gets, so any larger square overflows one
+        let grid = Grid::from_cells(geometry::Grid::new(3, 3, i32::MAX));
+        assert_eq!(i64::from(i32::MAX), grid.mini_grid_value(3, 3, 1));
+        assert_eq!(4 * i64::from(i32::MAX), grid.mini_grid_value(2, 2, 2));
+        let mut grid = Grid::from_cells(geometry::Grid::new(3, 3, i32::MAX));
+        assert_eq!(
+            Some(((1, 1, 3), 9 * i64::from(i32::MAX))),
+            grid.best_complex_spot_scored()
+        );
+        assert_eq!(
+            Some(((1, 1), 4 * i64::from(i32::MAX))),
+            grid.best_spot_of_size(2)
+        );
+        let grid = Grid::from_cells(geometry::Grid::new(3, 3, i32::MIN));
+        assert_eq!(9 * i64::from(i32::MIN), grid.mini_grid_value(1, 1, 3));
+        assert_eq!(Some((1, 1, 3)), grid.worst_complex_spot());
+        assert_eq!(Some((1, 1, 1)), grid.best_complex_spot_parallel());
+    }
+
+    #[test]
+    fn test_complex_spot_parallel() {
+        let mut grid = Grid::new(1308);
+        assert_eq!(Some((227, 199, 19)), grid.best_complex_spot_parallel());
+        assert_eq!(grid.best_complex_spot(), grid.best_complex_spot_parallel());
+        let grid = Grid::new_sized(18, 3);
+        assert_eq!(
+            Grid::new_sized(18, 3).best_complex_spot(),
+            grid.best_complex_spot_parallel()
+        );
+    }
+
+    #[test]
+    fn test_spot_of_size() {
+        let mut grid = Grid::new(18);
+        assert_eq!(Some(((33, 45), 29)), grid.best_spot_of_size(3));
+        assert_eq!(Some(((90, 269), 113)), grid.best_spot_of_size(16));
+        let mut grid = Grid::new(42);
+        assert_eq!(Some(((21, 61), 30)), grid.best_spot_of_size(3));
+        assert_eq!(Some(((232, 251), 119)), grid.best_spot_of_size(12));
+        let mut grid = Grid::new(1308);
+        assert_eq!(
+            Some((21, 41)),
+            grid.best_spot_of_size(3).map(|(spot, _)| spot)
+        );
+        assert_eq!(
+            Some((227, 199)),
+            grid.best_spot_of_size(19).map(|(spot, _)| spot)
+        );
+        assert_eq!(None, grid.best_spot_of_size(0));
+        assert_eq!(None, grid.best_spot_of_size(301));
+        let mut grid = Grid::new_sized(18, 2);
+        assert_eq!(None, grid.best_simple_spot());
+        assert!(grid.best_spot_of_size(2).is_some());
+    }
+
+    #[test]
+    fn test_empty_grid() {
+        let mut grid = Grid::new_sized(18, 0);
+        assert_eq!(0, grid.squares().count());
+        assert_eq!(None, grid.best_complex_spot_scored());
+        assert_eq!(None, grid.best_complex_spot_parallel());
+        assert_eq!(None, grid.worst_complex_spot());
+        assert_eq!(None, grid.best_spot_of_size(1));
+    }
+
+    #[test]
+    fn test_scored_spots() {
+        let mut grid = Grid::new(18);
+        assert_eq!(Some(((33, 45), 29)), grid.best_simple_spot_scored());
+        let mut grid = Grid::new(42);
+        assert_eq!(Some(((21, 61), 30)), grid.best_simple_spot_scored());
+        let mut grid = Grid::new(1308);
+        let ((x, y, size), score) = grid.best_complex_spot_scored().unwrap();
+        assert_eq!((227, 199, 19), (x, y, size));
+        assert_eq!(grid.mini_grid_value(x, y, size), score);
+    }
+
+    #[test]
+    fn test_squares() {
+        let mut grid = Grid::new(18);
+        let best_3x3 = grid
+            .squares()
+            .filter(|((_, _, size), _)| *size == 3)
+            .max_by_key(|(key, score)| (*score, Reverse(*key)))
+            .map(|((x, y, _), score)| ((x, y), score));
+        assert_eq!(grid.best_simple_spot_scored(), best_3x3);
+
+        let grid = Grid::new_sized(18, 3);
+        assert_eq!(14, grid.squares().count());
+        assert_eq!(
+            Some(((1, 1, 3), grid.vector_value(1, 1, 3, 3))),
+            grid.squares().find(|((_, _, size), _)| *size == 3)
+        );
+    }
+
+    #[test]
+    fn test_top_k_spots() {
+        let mut grid = Grid::new(18);
+        let top = grid.top_k_spots(5);
+        assert_eq!(5, top.len());
+        assert_eq!((grid.best_complex_spot().unwrap(), 113), top[0]);
+        assert!(top.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+        assert!(grid.top_k_spots(0).is_empty());
+
+        let grid = Grid::new_sized(18, 3);
+        assert_eq!(14, grid.top_k_spots(20).len());
+    }
+
+    #[test]
+    fn test_worst_complex_spot() {
+        let mut grid = Grid::new(18);
+        let (x, y, size) = grid.worst_complex_spot().unwrap();
+        let (_, best) = grid.best_complex_spot_scored().unwrap();
+        let worst = grid.mini_grid_value(x, y, size);
+        assert!(worst <= best);
+        assert!(grid.squares().all(|(_, score)| worst <= score));
+
+        let grid = Grid::from_cells(geometry::Grid::new(4, 4, -2));
+        assert_eq!(Some((1, 1, 4)), grid.worst_complex_spot());
+    }
+
+    #[test]
+    fn test_summed_area_reuse() {
+        let mut grid = Grid::new(18);
+        assert!(grid.sums.get().is_none());
+        let first = grid.best_spot_of_size(3);
+        let table: *const _ = grid.sums.get().unwrap();
+        assert_eq!(first, grid.best_spot_of_size(3));
+        assert!(std::ptr::eq(table, grid.sums.get().unwrap()));
+
+        let mut grid = Grid::new(42);
+        grid.precompute_summed_area();
+        let table: *const _ = grid.sums.get().unwrap();
+        grid.precompute_summed_area();
+        assert_eq!(Some(((21, 61), 30)), grid.best_simple_spot_scored());
+        assert!(std::ptr::eq(table, grid.sums.get().unwrap()));
+    }
+
+    #[test]
+    fn test_new_sized() {
+        let grid = Grid::new_sized(18, 20);
+        assert_eq!(20, grid.size);
+        assert_eq!(20, grid.cells.width());
+        assert_eq!(power_level(1, 1, 18), grid.cell(1, 1));
+        assert_eq!(power_level(20, 20, 18), grid.cell(20, 20));
+
+        // every square fits inside the smaller grid
+        let mut grid = Grid::new_sized(18, 20);
+        let ((x, y, size), score) = grid.best_complex_spot_scored().unwrap();
+        assert!(x >= 1 && y >= 1 && x + size <= 21 && y + size <= 21);
+        let mut best = i64::MIN;
+        for i in 1..=20 {
+            for j in 1..=20 {
+                for k in 1..=21 - usize::max(i, j) {
+                    best = i64::max(best, grid.vector_value(i, j, i + k - 1, j + k - 1));
+                }
+            }
+        }
+        assert_eq!(best, score);
+    }
+
+    #[test]
+    fn test_render_heatmap() {
+        let grid = Grid::new(18);
+        let heatmap = grid.render_heatmap();
+        let rows: Vec<&str> = heatmap.lines().collect();
+        assert_eq!(300, rows.len());
+        assert!(rows.iter().all(|row| row.len() == 300));
+        // the best 3x3 square for serial 18 starts at (33, 45)
+        assert_eq!("###", &rows[44][32..35]);
+        assert_eq!("++#", &rows[46][32..35]);
+
+        // (122, 79) has power -5 for serial 57 and (217, 196) has 0 for serial 39
+        assert_eq!(
+            Some('-'),
+            Grid::new(57)
+                .render_heatmap()
+                .lines()
+                .nth(78)
+                .unwrap()
+                .chars()
+                .nth(121)
+        );
+        assert_eq!(
+            Some('.'),
+            Grid::new(39)
+                .render_heatmap()
+                .lines()
+                .nth(195)
+                .unwrap()
+                .chars()
+                .nth(216)
+        );
     }
 
     #[test]
@@ -1,107 +1,574 @@
 use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::iter;
 
 use regex::Regex;
 
-#[derive(Debug)]
-struct Pots<'a> {
-    pots: Vec<char>,
+use crate::day::Day;
+use crate::error::AdventError;
+
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    pub(crate) line: String,
+}
+
+impl ParseError {
+    fn new(line: &str) -> ParseError {
+        ParseError {
+            line: line.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "could not parse pots: {}", self.line)
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Debug, Clone)]
+pub struct Pots {
+    pots: VecDeque<char>,
     offset: i64,
-    replacements: HashMap<&'a str, char>,
+    replacements: HashMap<String, char>,
+    // how many pots on each side of a pot decide what grows in it
+    radius: usize,
 }
 
-impl<'a> Pots<'a> {
-    #[allow(dead_code)]
-    fn new(s: &'a str) -> Pots {
+impl Pots {
+    pub fn new(s: &str) -> Result<Pots, ParseError> {
+        Pots::with_radius(s, 2)
+    }
+
+    // rules look at the 2 * radius + 1 pots around each pot
+    pub fn with_radius(s: &str, radius: usize) -> Result<Pots, ParseError> {
         lazy_static! {
             static ref RE: Regex = Regex::new(r"initial state: (?P<initial_state>[#\.]*)").unwrap();
+            static ref RULE_RE: Regex =
+                Regex::new(r"^(?P<ancestors>[#\.]+)\s*=>\s*(?P<child>[#\.])$").unwrap();
         }
-        let caps = RE.captures(s).unwrap();
-        let initial_state: Vec<char> = caps["initial_state"].trim().chars().collect();
-        let mut pots = vec!['.'; 5];
-        pots.extend(initial_state);
-        pots.extend(vec!['.'; 5]);
-
-        let offset = -5;
-
-        let mut replacements = HashMap::new();
-        for line in s.lines().skip(2) {
-            let key = &line[..5];
-            let value = line.chars().nth(9).unwrap();
-            replacements.insert(key, value);
+        let first_line = s.lines().next().unwrap_or("");
+        let caps = RE
+            .captures(first_line)
+            .ok_or_else(|| ParseError::new(first_line))?;
+        let initial: Vec<bool> = caps["initial_state"]
+            .trim()
+            .chars()
+            .map(|c| c == '#')
+            .collect();
+
+        let width = 2 * radius + 1;
+        let mut rules = HashMap::new();
+        for line in s.lines().skip(1).map(|line| line.trim()) {
+            if line.is_empty() {
+                continue;
+            }
+            let caps = RULE_RE
+                .captures(line)
+                .ok_or_else(|| ParseError::new(line))?;
+            let key = caps.name("ancestors").unwrap().as_str();
+            if key.len() != width {
+                return Err(ParseError::new(line));
+            }
+            let value = &caps["child"] == "#";
+            // a plant growing out of nothing would fill the endless row of empty pots
+            // on both sides, and its score would be infinite
+            if value && key.chars().all(|c| c == '.') {
+                return Err(ParseError::new(line));
+            }
+            rules.insert(key.to_string(), value);
         }
 
+        Ok(Pots::from_state_with_radius(&initial, rules, radius))
+    }
+
+    // seeds the pots directly, true for a plant. The first pot is pot 0
+    // rules are written like the input, "..#.#" for the pots around a pot, and all
+    // have to be the same length. They must not grow a plant out of all-empty pots
+    pub fn from_state(initial: &[bool], rules: HashMap<String, bool>) -> Pots {
+        let radius = rules.keys().next().map_or(2, |key| key.len() / 2);
+        Pots::from_state_with_radius(initial, rules, radius)
+    }
+
+    fn from_state_with_radius(
+        initial: &[bool],
+        rules: HashMap<String, bool>,
+        radius: usize,
+    ) -> Pots {
+        let width = 2 * radius + 1;
+        assert!(
+            rules.keys().all(|key| key.len() == width),
+            "every rule must cover {} pots",
+            width
+        );
+        let empty = ".".repeat(width);
+        assert!(
+            !rules.get(&empty).cloned().unwrap_or(false),
+            "plants cannot grow out of empty pots"
+        );
+
+        let mut pots: VecDeque<char> = VecDeque::with_capacity(initial.len() + 2 * width);
+        pots.extend(vec!['.'; width]);
+        pots.extend(initial.iter().map(|plant| if *plant { '#' } else { '.' }));
+        pots.extend(vec!['.'; width]);
+
+        let replacements = rules
+            .into_iter()
+            .map(|(key, plant)| (key, if plant { '#' } else { '.' }))
+            .collect();
+
         Pots {
             pots,
-            offset,
+            offset: -(width as i64),
             replacements,
+            radius,
         }
     }
 
-    #[allow(dead_code)]
-    fn next_gen(&mut self, generations: u64) -> i64 {
+    // next_gen moves these pots forward, so calling it twice runs both sets of generations
+    // score_after leaves them where they are
+    // once the plants start repeating the same shapes, the rest is extrapolated
+    pub fn score_after(&self, generations: u64) -> Result<i64, AdventError> {
+        self.score_after_capped(generations, STABILIZATION_CAP)
+    }
+
+    // like score_after, but gives up if more than cap generations are asked for and
+    // the shapes haven't repeated by then
+    pub fn score_after_capped(&self, generations: u64, cap: u64) -> Result<i64, AdventError> {
+        let (history, cycle) = self.run_until_cycle(u64::min(generations, cap));
+        match cycle {
+            None if generations > cap => Err(AdventError::NoCycle(cap)),
+            None => Ok(history[generations as usize].score),
+            Some((start, period)) => {
+                // the whole pattern moves by the same amount every period
+                let drift =
+                    history[(start + period) as usize].first - history[start as usize].first;
+                let periods = ((generations - start) / period) as i64;
+                let phase = &history[(start + (generations - start) % period) as usize];
+                Ok(phase.score + periods * drift * phase.count)
+            }
+        }
+    }
+
+    // (cycle_start, period) if the plants make a shape at cycle_start that they make
+    // again period generations later, possibly shifted along the row
+    pub fn find_cycle(&self, max_gen: u64) -> Option<(u64, u64)> {
+        self.run_until_cycle(max_gen).1
+    }
+
+    // summaries of every generation up to max_gen, or until a shape repeats
+    fn run_until_cycle(&self, max_gen: u64) -> (Vec<Summary>, Option<(u64, u64)>) {
+        let mut pots = self.clone();
+        let mut seen: HashMap<String, u64> = HashMap::new();
+        let mut history: Vec<Summary> = Vec::new();
+        for (generation, pattern, positions) in pots.generations(max_gen) {
+            history.push(Summary {
+                score: positions.iter().sum(),
+                count: positions.len() as i64,
+                first: positions.first().cloned().unwrap_or(0),
+            });
+            if let Some(start) = seen.get(&pattern) {
+                return (history, Some((*start, generation - start)));
+            }
+            seen.insert(pattern, generation);
+        }
+        (history, None)
+    }
+
+    // (generation, pattern, live positions) for these pots and each of the next max_gen
+    // generations, moving these pots forward as it goes
+    fn generations(&mut self, max_gen: u64) -> impl Iterator<Item = (u64, String, Vec<i64>)> + '_ {
+        (0..=max_gen).map(move |generation| {
+            if generation > 0 {
+                self.next_gen(1);
+            }
+            (generation, self.pattern(), self.live_positions())
+        })
+    }
+
+    pub fn next_gen(&mut self, generations: u64) -> i64 {
+        let radius = self.radius;
         for _ in 0..generations {
             let current_gen: String = self.pots.iter().collect();
-            for i in 2..self.pots.len() - 2 {
-                let ancestors = &current_gen[i - 2..=i + 2];
+            for i in radius..self.pots.len() - radius {
+                let ancestors = &current_gen[i - radius..=i + radius];
 
                 self.pots[i] = match self.replacements.get(ancestors) {
                     Some(child) => *child,
                     None => '.',
                 };
             }
-            // left-pad
-            let left = self.pots[..5].iter().filter(|x| **x == '#').count();
-            for _ in 0..left {
-                self.pots.insert(0, '.');
+            // a plant can grow up to radius pots past the outermost plant, and those pots
+            // need radius more beside them to be updated, so both ends keep 2 * radius empty pots
+            let left = self.pots.iter().take_while(|x| **x == '.').count();
+            for _ in left..2 * radius {
+                self.pots.push_front('.');
             }
-            self.offset -= left as i64;
+            self.offset -= (2 * radius).saturating_sub(left) as i64;
 
-            let right = self.pots[self.pots.len() - 5..]
-                .iter()
-                .filter(|x| **x == '#')
-                .count();
-            self.pots.extend(vec!['.'; right]);
+            let right = self.pots.iter().rev().take_while(|x| **x == '.').count();
+            for _ in right..2 * radius {
+                self.pots.push_back('.');
+            }
         }
+        self.live_positions().iter().sum()
+    }
+
+    // the score after each generation from here on, starting with the next one
+    // moves these pots forward as it goes, like next_gen
+    pub fn scores(&mut self) -> impl Iterator<Item = i64> + '_ {
+        iter::repeat_with(move || self.next_gen(1))
+    }
+
+    // the numbers of the pots with plants in them
+    pub fn live_positions(&self) -> Vec<i64> {
         self.pots
             .iter()
             .enumerate()
             .filter(|(_, x)| **x == '#')
             .map(|(i, _)| i as i64 + self.offset)
-            .sum()
+            .collect()
+    }
+
+    // the pots in a row, prefixed by the number of the leftmost one
+    pub fn render(&self) -> String {
+        let pots: String = self.pots.iter().collect();
+        format!("{}: {}", self.offset, pots)
     }
 
-    #[allow(dead_code)]
-    fn next_gen_fast(&mut self, generations: i64) -> i64 {
-        let after_100 = self.next_gen(100);
-        let after_101 = self.next_gen(1);
-        after_100 + (after_101 - after_100) * (generations - 100)
+    // (generation, pattern, score change per generation) for the first pattern that
+    // the next generation repeats, shifted along the row. these pots are left one
+    // generation after it. None if no pattern repeats within max_gen generations
+    pub fn stabilized_pattern(&mut self, max_gen: u64) -> Option<(u64, String, i64)> {
+        let mut previous: Option<(String, i64)> = None;
+        for (generation, pattern, positions) in self.generations(max_gen) {
+            let score: i64 = positions.iter().sum();
+            if let Some((previous_pattern, previous_score)) = previous {
+                if pattern == previous_pattern {
+                    return Some((generation - 1, pattern, score - previous_score));
+                }
+            }
+            previous = Some((pattern, score));
+        }
+        None
+    }
+
+    // the plants without the empty pots around them, which doesn't change when they drift
+    fn pattern(&self) -> String {
+        let pots: String = self.pots.iter().collect();
+        pots.trim_matches('.').to_string()
+    }
+}
+
+const STABILIZATION_CAP: u64 = 1000;
+
+struct Summary {
+    score: i64,
+    count: i64,
+    first: i64,
+}
+
+pub struct Day12;
+
+impl Day for Day12 {
+    fn part1(&self, input: &str) -> Result<String, AdventError> {
+        Ok(Pots::new(input)?.score_after(20)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String, AdventError> {
+        Ok(Pots::new(input)?.score_after(50000000000)?.to_string())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Pots;
+    use super::Day12;
+    use super::{ParseError, Pots};
+    use crate::day::Day;
+    use crate::error::AdventError;
+    use crate::input::read_input;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_day() {
+        assert_eq!(Ok(String::from("325")), Day12.part1(TEST_INPUT));
+        assert_eq!(Ok(String::from("3738")), Day12.part1(&REAL_INPUT));
+        assert_eq!(Ok(String::from("3900000002467")), Day12.part2(&REAL_INPUT));
+        assert_eq!(
+            Err(AdventError::Parse(String::from("#..# => #"))),
+            Day12.part1("#..# => #")
+        );
+    }
 
     #[test]
     fn test_parsing() {
-        let pots = Pots::new(TEST_INPUT);
+        let pots = Pots::new(TEST_INPUT).unwrap();
         assert_eq!(35, pots.pots.len());
         assert_eq!(14, pots.replacements.len());
         assert_eq!(-5, pots.offset);
     }
 
+    #[test]
+    fn test_parse_errors() {
+        let input = "initial state: #..#.#..##......###...###
+
+...##  =>  #
+..#..=>#
+.#... => #
+
+";
+        let pots = Pots::new(input).unwrap();
+        assert_eq!(3, pots.replacements.len());
+        assert_eq!(Some(&'#'), pots.replacements.get("..#.."));
+
+        assert_eq!(
+            Some(ParseError::new("..# => #")),
+            Pots::new("initial state: #..#\n\n..# => #").err()
+        );
+        assert_eq!(
+            Some(ParseError::new("#..# => #")),
+            Pots::new("#..# => #").err()
+        );
+        assert!(Pots::new("").is_err());
+    }
+
+    #[test]
+    fn test_spontaneous_plants() {
+        assert_eq!(
+            Some(ParseError::new("..... => #")),
+            Pots::new("initial state: #..#\n\n..#.. => #\n..... => #").err()
+        );
+        assert_eq!(
+            Some(ParseError::new("... => #")),
+            Pots::with_radius("initial state: #..#\n\n... => #", 1).err()
+        );
+        let pots = Pots::new("initial state: #..#\n\n..... => .\n..#.. => #").unwrap();
+        assert_eq!(Ok(3), pots.score_after(20));
+    }
+
+    #[test]
+    fn test_scores() {
+        let mut pots = Pots::new(TEST_INPUT).unwrap();
+        let scores: Vec<i64> = pots.scores().take(21).collect();
+        assert_eq!(325, scores[19]);
+        assert_eq!(Pots::new(TEST_INPUT).unwrap().score_after(1), Ok(scores[0]));
+        // the pots were moved on by all 21 generations
+        assert_eq!(scores[20], pots.next_gen(0));
+
+        // once the plants settle they drift along, adding the same each generation
+        let mut pots = Pots::new(&REAL_INPUT).unwrap();
+        let scores: Vec<i64> = pots.scores().skip(150).take(3).collect();
+        assert_eq!(scores[1] - scores[0], scores[2] - scores[1]);
+        assert_eq!(78, scores[1] - scores[0]);
+    }
+
+    #[test]
+    fn test_from_state() {
+        // every plant moves one pot to the right each generation
+        let rules: HashMap<String, bool> =
+            vec![(String::from(".#..."), true)].into_iter().collect();
+        let mut pots = Pots::from_state(&[true, false, false, false, true], rules);
+        assert_eq!(4, pots.next_gen(0));
+        assert_eq!(10, pots.next_gen(3));
+        assert_eq!(vec![3, 7], pots.live_positions());
+
+        let rules: HashMap<String, bool> = TEST_INPUT
+            .lines()
+            .skip(2)
+            .map(|line| (line[..5].to_string(), line.ends_with('#')))
+            .collect();
+        let initial: Vec<bool> = "#..#.#..##......###...###"
+            .chars()
+            .map(|c| c == '#')
+            .collect();
+        let pots = Pots::from_state(&initial, rules);
+        assert_eq!(Ok(325), pots.score_after(20));
+    }
+
+    #[test]
+    fn test_edge_padding() {
+        // each plant grows a new one two pots to its left and dies, which soon needs
+        // more empty pots on the left than the row started with
+        let rules: HashMap<String, bool> =
+            vec![(String::from("....#"), true)].into_iter().collect();
+        let pots = Pots::from_state(&[true], rules);
+        assert_eq!(Ok(-6), pots.score_after(3));
+        assert_eq!(Ok(-200), pots.score_after(100));
+        let mut pots = pots.clone();
+        assert_eq!(-6, pots.next_gen(3));
+        assert_eq!(vec![-6], pots.live_positions());
+        assert_eq!(-200, pots.next_gen(97));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_state_spontaneous() {
+        let rules: HashMap<String, bool> =
+            vec![(String::from("....."), true)].into_iter().collect();
+        Pots::from_state(&[true], rules);
+    }
+
+    #[test]
+    fn test_radius() {
+        // each pot becomes the exclusive or of its neighbours, drawing a Sierpinski triangle
+        let input = "initial state: #
+
+##. => #
+#.. => #
+.## => #
+..# => #";
+        let mut pots = Pots::with_radius(input, 1).unwrap();
+        assert_eq!(-3, pots.offset);
+        assert_eq!(vec![0], pots.live_positions());
+        pots.next_gen(1);
+        assert_eq!(vec![-1, 1], pots.live_positions());
+        pots.next_gen(1);
+        assert_eq!(vec![-2, 2], pots.live_positions());
+        pots.next_gen(1);
+        assert_eq!(vec![-3, -1, 1, 3], pots.live_positions());
+        pots.next_gen(4);
+        assert_eq!(
+            (-7..=7).step_by(2).collect::<Vec<i64>>(),
+            pots.live_positions()
+        );
+
+        // the triangle keeps growing, so its shape never repeats
+        assert_eq!(
+            Err(AdventError::NoCycle(1000)),
+            pots.score_after(50000000000)
+        );
+
+        assert_eq!(
+            Some(ParseError::new("...## => #")),
+            Pots::with_radius("initial state: #\n\n...## => #", 1).err()
+        );
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        // alternates between ##.# and ### while moving one pot left every generation
+        let input = "initial state: ##.#
+
+##. => #
+.## => #
+..# => #";
+        let pots = Pots::with_radius(input, 1).unwrap();
+        assert_eq!(Some((0, 2)), pots.find_cycle(100));
+        assert_eq!(None, pots.find_cycle(1));
+        assert_eq!(Ok(4), pots.score_after(0));
+        assert_eq!(Ok(-2), pots.score_after(2));
+        assert_eq!(Ok(-8), pots.score_after(4));
+        assert_eq!(Ok(-12), pots.score_after(5));
+        assert_eq!(Ok(-149999999996), pots.score_after(50000000000));
+        assert_eq!(Ok(-3000000000), pots.score_after(1000000001));
+
+        // settles into a two generation cycle after a couple of generations
+        let input = "initial state: ##.#
+
+##. => #
+#.. => #
+.## => #";
+        let pots = Pots::with_radius(input, 1).unwrap();
+        assert_eq!(Some((2, 2)), pots.find_cycle(100));
+        assert_eq!(Ok(5), pots.score_after(1));
+        assert_eq!(Ok(24), pots.score_after(6));
+        assert_eq!(Ok(4000000000), pots.score_after(1000000000));
+
+        let pots = Pots::new(&REAL_INPUT).unwrap();
+        assert_eq!(Ok(3738), pots.score_after(20));
+        assert_eq!(Ok(3900000002467), pots.score_after(50000000000));
+    }
+
     #[test]
     fn test_next_gen() {
-        let mut pots = Pots::new(TEST_INPUT);
+        let mut pots = Pots::new(TEST_INPUT).unwrap();
         assert_eq!(325, pots.next_gen(20));
-        let mut pots = Pots::new(REAL_INPUT);
+        let mut pots = Pots::new(&REAL_INPUT).unwrap();
         assert_eq!(3738, pots.next_gen(20));
     }
 
     #[test]
-    fn test_next_gen_fast() {
-        let mut pots = Pots::new(REAL_INPUT);
-        assert_eq!(3900000002467, pots.next_gen_fast(50000000000));
+    fn test_score_after() {
+        let pots = Pots::new(TEST_INPUT).unwrap();
+        assert_eq!(Ok(325), pots.score_after(20));
+        assert_eq!(Ok(325), pots.score_after(20));
+        assert_eq!(Ok(145), pots.score_after(0));
+
+        let mut pots = Pots::new(&REAL_INPUT).unwrap();
+        assert_eq!(Ok(3738), pots.score_after(20));
+        assert_eq!(3738, pots.next_gen(20));
+        assert_eq!(pots.score_after(0), Ok(pots.next_gen(0)));
+        assert_ne!(Ok(3738), pots.score_after(20));
+    }
+
+    #[test]
+    fn test_live_positions() {
+        let mut pots = Pots::new(TEST_INPUT).unwrap();
+        assert_eq!(
+            vec![0, 3, 5, 8, 9, 16, 17, 18, 22, 23, 24],
+            pots.live_positions()
+        );
+        assert_eq!("-5: .....#..#.#..##......###...###.....", pots.render());
+
+        pots.next_gen(20);
+        let positions = pots.live_positions();
+        assert_eq!(
+            vec![-2, 3, 4, 9, 10, 11, 12, 13, 17, 18, 19, 20, 21, 22, 23, 28, 30, 33, 34],
+            positions
+        );
+        assert_eq!(325, positions.iter().sum::<i64>());
+        // the row keeps 4 empty pots at each end, enough for any plant that could grow
+        assert_eq!(
+            "-6: ....#....##....#####...#######....#.#..##....",
+            pots.render()
+        );
+    }
+
+    #[test]
+    fn test_score_after_capped() {
+        let pots = Pots::new(&REAL_INPUT).unwrap();
+        assert_eq!(
+            Ok(3900000002467),
+            pots.score_after_capped(50000000000, 1000)
+        );
+        assert_eq!(Ok(3738), pots.score_after_capped(20, 20));
+        assert_eq!(
+            Err(AdventError::NoCycle(10)),
+            pots.score_after_capped(20, 10)
+        );
+        assert_eq!(
+            Err(AdventError::NoCycle(10)),
+            pots.score_after_capped(50000000000, 10)
+        );
+        let pots = Pots::new(TEST_INPUT).unwrap();
+        assert_eq!(Ok(999999999374), pots.score_after_capped(50000000000, 1000));
+    }
+
+    #[test]
+    fn test_stabilized_pattern() {
+        let mut pots = Pots::new(&REAL_INPUT).unwrap();
+        let (generation, pattern, delta) = pots.stabilized_pattern(1000).unwrap();
+        assert_eq!((99, 78), (generation, delta));
+        // every plant moves one pot to the right each generation
+        assert_eq!(delta, pattern.matches('#').count() as i64);
+        assert!(pattern.starts_with("###.##.##.#"));
+        // the same shape score_after finds repeating every generation
+        assert_eq!(
+            Some((99, 1)),
+            Pots::new(&REAL_INPUT).unwrap().find_cycle(1000)
+        );
+        // and the pots are left one generation after it
+        assert_eq!(
+            Pots::new(&REAL_INPUT).unwrap().score_after(100),
+            Ok(pots.next_gen(0))
+        );
+
+        let mut pots = Pots::new(TEST_INPUT).unwrap();
+        assert_eq!(None, pots.stabilized_pattern(10));
     }
 
     const TEST_INPUT: &str = "initial state: #..#.#..##......###...###
@@ -121,38 +588,7 @@ mod tests {
 ###.# => #
 ####. => #";
 
-    const REAL_INPUT: &str = "initial state: .##..#.#..##..##..##...#####.#.....#..#..##.###.#.####......#.......#..###.#.#.##.#.#.###...##.###.#
-
-.##.# => #
-##.#. => #
-##... => #
-#.... => .
-.#..# => .
-#.##. => .
-.##.. => .
-.#.## => .
-###.. => .
-..##. => #
-##### => #
-#...# => #
-.#... => #
-###.# => #
-#.### => #
-##..# => .
-.###. => #
-...## => .
-..#.# => .
-##.## => #
-....# => .
-#.#.# => #
-#.#.. => .
-.#### => .
-...#. => #
-..### => .
-..#.. => #
-..... => .
-####. => .
-#..## => #
-.#.#. => .
-#..#. => #";
+    lazy_static! {
+        static ref REAL_INPUT: String = read_input(12).unwrap();
+    }
 }
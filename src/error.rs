@@ -0,0 +1,119 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use crate::day04;
+use crate::day12;
+
+// The errors returned when a puzzle input can't be solved, shared by every day
+#[derive(Debug, PartialEq)]
+pub enum AdventError {
+    // the input, or the line of it, that could not be parsed
+    Parse(String),
+    // a line that parsed, but doesn't make sense after the ones before it, and why
+    Invalid { line: String, reason: &'static str },
+    // reading the input failed before it could be parsed
+    Io(String),
+    // steps that depend on each other, in the order they were found
+    Cycle(Vec<String>),
+    // steps still waiting on others when no more work can be done, in order
+    Deadlock(Vec<String>),
+    // a simulation that didn't repeat itself within this many generations
+    NoCycle(u64),
+    // input that parsed, but has nothing that answers the puzzle
+    NoAnswer(String),
+}
+
+impl fmt::Display for AdventError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AdventError::Parse(line) => write!(f, "could not parse: {}", line),
+            AdventError::Invalid { line, reason } => write!(f, "{}: {}", reason, line),
+            AdventError::Io(e) => write!(f, "could not read input: {}", e),
+            AdventError::Cycle(steps) => write!(f, "steps form a cycle: {}", steps.join(" -> ")),
+            AdventError::Deadlock(steps) => {
+                write!(f, "steps can never start: {}", steps.join(", "))
+            }
+            AdventError::NoCycle(generations) => {
+                write!(f, "nothing repeated within {} generations", generations)
+            }
+            AdventError::NoAnswer(reason) => write!(f, "no answer: {}", reason),
+        }
+    }
+}
+
+impl Error for AdventError {}
+
+impl From<io::Error> for AdventError {
+    fn from(e: io::Error) -> AdventError {
+        AdventError::Io(e.to_string())
+    }
+}
+
+impl From<day04::ParseError> for AdventError {
+    fn from(e: day04::ParseError) -> AdventError {
+        match e.reason {
+            None => AdventError::Parse(e.line),
+            Some(reason) => AdventError::Invalid {
+                line: e.line,
+                reason,
+            },
+        }
+    }
+}
+
+impl From<day12::ParseError> for AdventError {
+    fn from(e: day12::ParseError) -> AdventError {
+        AdventError::Parse(e.line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AdventError;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            "could not parse: 1 1",
+            AdventError::Parse(String::from("1 1")).to_string()
+        );
+        assert_eq!(
+            "woke up while awake: [1518-11-01 00:25] wakes up",
+            AdventError::Invalid {
+                line: String::from("[1518-11-01 00:25] wakes up"),
+                reason: "woke up while awake"
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "could not read input: disk on fire",
+            AdventError::Io(String::from("disk on fire")).to_string()
+        );
+        assert_eq!(
+            "steps form a cycle: A -> B -> D",
+            AdventError::Cycle(vec![
+                String::from("A"),
+                String::from("B"),
+                String::from("D")
+            ])
+            .to_string()
+        );
+        assert_eq!(
+            "steps can never start: A, C",
+            AdventError::Deadlock(vec![String::from("A"), String::from("C")]).to_string()
+        );
+        assert_eq!(
+            "nothing repeated within 1000 generations",
+            AdventError::NoCycle(1000).to_string()
+        );
+        assert_eq!(
+            "no answer: no ids",
+            AdventError::NoAnswer(String::from("no ids")).to_string()
+        );
+        assert_eq!(
+            "steps form a cycle: fetch -> build",
+            AdventError::Cycle(vec![String::from("fetch"), String::from("build")]).to_string()
+        );
+    }
+}
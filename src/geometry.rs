@@ -0,0 +1,288 @@
+// Points, distances and grids shared by the days that work on a plane
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum Metric {
+    #[default]
+    Manhattan,
+    Euclidean,
+    Chebyshev,
+}
+
+// which of a set of points is nearest to some other point
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Owner {
+    Single(Point),
+    // two or more points tie for the shortest distance
+    Tied,
+    // there were no points to choose from
+    None,
+}
+
+impl Owner {
+    pub fn point(&self) -> Option<Point> {
+        match self {
+            Owner::Single(point) => Some(*point),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub fn new(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+
+    // distances are compared exactly, so Euclidean near-ties aren't rounded into ties
+    pub fn nearest_point(&self, points: &[Point], metric: Metric) -> Owner {
+        let mut min_distance = u128::MAX;
+        let mut closest = Owner::None;
+        let mut tied = false;
+        for point in points.iter() {
+            let distance = self.exact_distance(point, metric);
+            if distance < min_distance {
+                closest = Owner::Single(*point);
+                min_distance = distance;
+                tied = false;
+            } else if distance == min_distance {
+                tied = true;
+            }
+        }
+        if tied {
+            Owner::Tied
+        } else {
+            closest
+        }
+    }
+
+    // Euclidean distances are summed exactly and only the total is rounded
+    pub fn total_distance(&self, points: &[Point], metric: Metric) -> usize {
+        match metric {
+            Metric::Euclidean => points
+                .iter()
+                .map(|point| euclidean_distance(self, point))
+                .sum::<f64>()
+                .round() as usize,
+            _ => points
+                .iter()
+                .map(|point| self.distance_with(point, metric))
+                .sum(),
+        }
+    }
+
+    pub fn distance(&self, other: &Point) -> usize {
+        manhattan_distance(self, other)
+    }
+
+    // a distance that orders points the same way as distance_with, without rounding
+    // Euclidean distances are squared instead
+    fn exact_distance(&self, other: &Point, metric: Metric) -> u128 {
+        match metric {
+            Metric::Euclidean => {
+                let dx = u128::from((self.x - other.x).unsigned_abs());
+                let dy = u128::from((self.y - other.y).unsigned_abs());
+                dx * dx + dy * dy
+            }
+            _ => self.distance_with(other, metric) as u128,
+        }
+    }
+
+    // Euclidean distances are rounded to the nearest whole tile
+    pub fn distance_with(&self, other: &Point, metric: Metric) -> usize {
+        match metric {
+            Metric::Manhattan => manhattan_distance(self, other),
+            Metric::Euclidean => euclidean_distance(self, other).round() as usize,
+            Metric::Chebyshev => chebyshev_distance(self, other),
+        }
+    }
+}
+
+// A point in a volume, which only has Manhattan distances
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub fn new(x: i64, y: i64, z: i64) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    // None if two or more points tie for the shortest distance
+    pub fn nearest_point(&self, points: &[Point3]) -> Option<Point3> {
+        let mut min_distance = usize::MAX;
+        let mut closest: Option<Point3> = None;
+        let mut tied = false;
+        for point in points.iter() {
+            let distance = self.distance(point);
+            if distance < min_distance {
+                closest = Some(*point);
+                min_distance = distance;
+                tied = false;
+            } else if distance == min_distance {
+                tied = true;
+            }
+        }
+        if tied {
+            None
+        } else {
+            closest
+        }
+    }
+
+    pub fn total_distance(&self, points: &[Point3]) -> usize {
+        points.iter().map(|point| self.distance(point)).sum()
+    }
+
+    pub fn distance(&self, other: &Point3) -> usize {
+        ((self.x - other.x).unsigned_abs()
+            + (self.y - other.y).unsigned_abs()
+            + (self.z - other.z).unsigned_abs()) as usize
+    }
+}
+
+pub fn manhattan_distance(a: &Point, b: &Point) -> usize {
+    ((a.x - b.x).unsigned_abs() + (a.y - b.y).unsigned_abs()) as usize
+}
+
+pub fn euclidean_distance(a: &Point, b: &Point) -> f64 {
+    let dx = (a.x - b.x) as f64;
+    let dy = (a.y - b.y) as f64;
+    (dx * dx + dy * dy).sqrt()
+}
+
+pub fn chebyshev_distance(a: &Point, b: &Point) -> usize {
+    u64::max((a.x - b.x).unsigned_abs(), (a.y - b.y).unsigned_abs()) as usize
+}
+
+// A width x height rectangle of cells stored row after row in one Vec
+#[derive(Debug, Clone)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    // takes signed coordinates so neighbours of edge cells can be checked too
+    pub fn in_bounds(&self, x: i64, y: i64) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> &T {
+        &self.cells[self.index(x, y)]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: T) {
+        let index = self.index(x, y);
+        self.cells[index] = value;
+    }
+
+    // every cell, row after row
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    fn index(&self, x: usize, y: usize) -> usize {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is outside a {}x{} grid",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+        x + y * self.width
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        chebyshev_distance, euclidean_distance, manhattan_distance, Grid, Metric, Owner, Point,
+    };
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(1, 1);
+        let b = Point::new(4, -3);
+        assert_eq!(7, manhattan_distance(&a, &b));
+        assert_eq!(5.0, euclidean_distance(&a, &b));
+        assert_eq!(4, chebyshev_distance(&a, &b));
+        assert_eq!(0, manhattan_distance(&a, &a));
+
+        let points = [Point::new(1, 1), Point::new(-1, 1)];
+        assert_eq!(
+            3,
+            Point::new(0, 0).total_distance(&points, Metric::Euclidean)
+        );
+        assert_eq!(
+            4,
+            Point::new(0, 0).total_distance(&points, Metric::Manhattan)
+        );
+    }
+
+    #[test]
+    fn test_nearest_point_euclidean() {
+        // both round to 2, but (2, 0) is 2.0 away and (2, 1) is 2.236 away
+        let points = [Point::new(2, 1), Point::new(2, 0)];
+        assert_eq!(
+            Owner::Single(Point::new(2, 0)),
+            Point::new(0, 0).nearest_point(&points, Metric::Euclidean)
+        );
+        let points = [Point::new(2, 1), Point::new(1, 2)];
+        assert_eq!(
+            Owner::Tied,
+            Point::new(0, 0).nearest_point(&points, Metric::Euclidean)
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let mut grid = Grid::new(3, 2, 0);
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        grid.set(2, 1, 5);
+        grid.set(0, 1, 3);
+        assert_eq!(5, *grid.get(2, 1));
+        assert_eq!(
+            vec![0, 0, 0, 3, 0, 5],
+            grid.iter().cloned().collect::<Vec<i32>>()
+        );
+        assert!(grid.in_bounds(2, 1));
+        assert!(!grid.in_bounds(3, 1));
+        assert!(!grid.in_bounds(0, 2));
+        assert!(!grid.in_bounds(-1, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_out_of_bounds() {
+        let grid = Grid::new(3, 2, 0);
+        grid.get(3, 0);
+    }
+}
@@ -0,0 +1,26 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+// puzzle inputs live in inputs/dayNN.txt, relative to where the program is run
+pub fn read_input(day: u32) -> io::Result<String> {
+    fs::read_to_string(input_path(day))
+}
+
+fn input_path(day: u32) -> PathBuf {
+    PathBuf::from("inputs").join(format!("day{:02}.txt", day))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_read_input() {
+        assert_eq!(PathBuf::from("inputs/day07.txt"), super::input_path(7));
+        assert_eq!(PathBuf::from("inputs/day12.txt"), super::input_path(12));
+        let input = super::read_input(11).unwrap();
+        assert_eq!("1308", input.trim());
+        assert!(super::read_input(25).is_err());
+    }
+}
@@ -0,0 +1,21 @@
+#[macro_use]
+extern crate lazy_static;
+
+pub mod day;
+mod day01;
+pub mod day02;
+mod day03;
+pub mod day04;
+mod day05;
+pub mod day06;
+pub mod day07;
+mod day08;
+pub mod day09;
+mod day10;
+pub mod day11;
+pub mod day12;
+pub mod error;
+pub mod geometry;
+pub mod input;
+#[cfg(test)]
+mod test_util;
@@ -1,19 +1,122 @@
-#[macro_use]
-extern crate lazy_static;
-
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::process;
+
+use advent::day;
+
+const USAGE: &str = "usage: advent-2018 --day <day> --part <1|2> [--input <path>]
+reads the puzzle input from stdin if no input file is given";
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    day: u32,
+    part: u32,
+    input: Option<String>,
+}
 
 fn main() {
-    println!("Hello, world!");
+    let args: Vec<String> = env::args().skip(1).collect();
+    match run(&args) {
+        Ok(answer) => println!("{}", answer),
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            process::exit(1);
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<String, String> {
+    let options = parse_args(args)?;
+    let solution =
+        day::find_day(options.day).ok_or_else(|| format!("day {} has no solution", options.day))?;
+
+    let input = match options.input {
+        Some(path) => fs::read_to_string(&path).map_err(|e| format!("{}: {}", path, e))?,
+        None => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| e.to_string())?;
+            input
+        }
+    };
+
+    match options.part {
+        1 => solution.part1(&input).map_err(|e| e.to_string()),
+        _ => solution.part2(&input).map_err(|e| e.to_string()),
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut day: Option<u32> = None;
+    let mut part: Option<u32> = None;
+    let mut input: Option<String> = None;
+
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| format!("{} needs a value", flag))?;
+        match flag.as_str() {
+            "--day" => day = Some(value.parse().map_err(|_| format!("bad day: {}", value))?),
+            "--part" => part = Some(value.parse().map_err(|_| format!("bad part: {}", value))?),
+            "--input" => input = Some(value.clone()),
+            _ => return Err(format!("unknown flag: {}", flag)),
+        }
+    }
+
+    let day = day.ok_or("--day is required")?;
+    let part = part.ok_or("--part is required")?;
+    if part != 1 && part != 2 {
+        return Err(format!("bad part: {}", part));
+    }
+    Ok(Options { day, part, input })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_args, run, Options};
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            Ok(Options {
+                day: 7,
+                part: 2,
+                input: Some(String::from("path.txt"))
+            }),
+            parse_args(&args("--day 7 --part 2 --input path.txt"))
+        );
+        assert_eq!(
+            Ok(Options {
+                day: 12,
+                part: 1,
+                input: None
+            }),
+            parse_args(&args("--part 1 --day 12"))
+        );
+        assert!(parse_args(&args("--day 7")).is_err());
+        assert!(parse_args(&args("--day 7 --part 3")).is_err());
+        assert!(parse_args(&args("--day seven --part 1")).is_err());
+        assert!(parse_args(&args("--day 7 --part")).is_err());
+        assert!(parse_args(&args("--night 7 --part 1")).is_err());
+    }
+
+    #[test]
+    fn test_run() {
+        assert_eq!(
+            Ok(String::from("BHMOTUFLCPQKWINZVRXAJDSYEG")),
+            run(&args("--day 7 --part 1 --input inputs/day07.txt"))
+        );
+        assert!(run(&args("--day 1 --part 1 --input inputs/day01.txt")).is_err());
+        assert!(run(&args("--day 7 --part 1 --input inputs/missing.txt")).is_err());
+        // input for the wrong day fails the same way as a missing file
+        assert!(run(&args("--day 9 --part 1 --input inputs/day07.txt")).is_err());
+        assert!(run(&args("--day 11 --part 2 --input inputs/day07.txt")).is_err());
+    }
 }
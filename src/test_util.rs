@@ -0,0 +1,18 @@
+// Helpers shared by the tests of several days
+
+// pseudo-random numbers from a fixed seed, so large generated inputs replay the same way
+pub struct Seeded {
+    state: u64,
+}
+
+impl Seeded {
+    pub fn new(seed: u64) -> Seeded {
+        Seeded { state: seed }
+    }
+
+    // the next number, from 0 up to but not including bound
+    pub fn below(&mut self, bound: u64) -> u64 {
+        self.state = self.state.wrapping_mul(6364136223846793005).wrapping_add(1);
+        (self.state >> 33) % bound
+    }
+}